@@ -0,0 +1,587 @@
+//! Local JSON-RPC 2.0 control API, off unless started with `--api-port`.
+//! Requests and responses are one JSON object per line over TCP on
+//! 127.0.0.1, and every request carries the token from `<data dir>/api-token`
+//! in its params.
+
+use std::fs::{self, OpenOptions};
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{Ipv4Addr, SocketAddr, TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::app::App;
+use crate::game::{calculate_bulk_cost, Producer, Upgrade};
+use crate::save::get_data_dir;
+
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+/// The game refused the action, e.g. not enough energy
+const REFUSED: i64 = -32000;
+const UNAUTHORIZED: i64 = -32001;
+/// The instance has the save open with `--read-only`
+const READ_ONLY: i64 = -32002;
+
+/// How long a connection waits for the game loop to answer
+const REPLY_TIMEOUT: Duration = Duration::from_secs(5);
+/// Longest request line; a client sending more is answered with an error and dropped
+const MAX_REQUEST_BYTES: u64 = 64 * 1024;
+/// Connections served at once; more are closed as soon as they're accepted
+const MAX_CONNECTIONS: usize = 16;
+
+#[derive(Debug, PartialEq)]
+pub struct RpcError {
+    code: i64,
+    message: String,
+}
+
+impl RpcError {
+    fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+/// A request that passed the token check, waiting for the game loop
+struct Call {
+    method: String,
+    params: Value,
+    reply: Sender<Result<Value, RpcError>>,
+}
+
+/// The listening API. Connections are served on their own threads; the
+/// calls they make are run on the game loop by `handle_pending`.
+pub struct ApiServer {
+    pub addr: SocketAddr,
+    calls: Receiver<Call>,
+}
+
+impl ApiServer {
+    /// Listen on `127.0.0.1:port` (any free port for 0), creating the token
+    /// file if there isn't one yet
+    pub fn start(port: u16) -> io::Result<Self> {
+        let token = load_or_create_token()?;
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port))?;
+        let addr = listener.local_addr()?;
+        let (sender, calls) = mpsc::channel();
+        thread::spawn(move || accept_connections(listener, token, sender));
+        Ok(Self { addr, calls })
+    }
+
+    /// Run the calls that arrived since the last time, without blocking.
+    /// `ran` sees each call that may change the game before it runs.
+    pub fn handle_pending(&self, app: &mut App, mut ran: impl FnMut(&str, &Value)) {
+        while let Ok(call) = self.calls.try_recv() {
+            let result = check_read_only(app, &call.method).and_then(|()| {
+                if mutates(&call.method) {
+                    ran(&call.method, &call.params);
+                }
+                dispatch(app, &call.method, call.params)
+            });
+            app.needs_redraw = true;
+            // The connection may have timed out and gone away
+            let _ = call.reply.send(result);
+        }
+    }
+}
+
+pub fn get_token_path() -> io::Result<PathBuf> {
+    Ok(get_data_dir()?.join("api-token"))
+}
+
+/// The token clients must send, generated on first use and kept so scripts
+/// can read it once
+fn load_or_create_token() -> io::Result<String> {
+    let path = get_token_path()?;
+    match fs::read_to_string(&path) {
+        Ok(token) if !token.trim().is_empty() => return Ok(token.trim().to_string()),
+        Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e),
+        _ => {}
+    }
+
+    let token = generate_token();
+    let mut options = OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    let mut file = options.open(&path)?;
+    file.write_all(token.as_bytes())?;
+    file.write_all(b"\n")?;
+    Ok(token)
+}
+
+/// 32 random bytes as hex, from the OS where it offers a random device
+fn generate_token() -> String {
+    let mut bytes = [0u8; 32];
+    let from_os = fs::File::open("/dev/urandom")
+        .and_then(|mut random| random.read_exact(&mut bytes))
+        .is_ok();
+    if !from_os {
+        // RandomState is seeded from the OS's randomness on every platform
+        use std::hash::{BuildHasher, Hasher};
+        for (i, chunk) in bytes.chunks_mut(8).enumerate() {
+            let mut hasher = std::collections::hash_map::RandomState::new().build_hasher();
+            hasher.write_usize(i);
+            hasher.write_u128(
+                std::time::UNIX_EPOCH
+                    .elapsed()
+                    .unwrap_or_default()
+                    .as_nanos(),
+            );
+            chunk.copy_from_slice(&hasher.finish().to_le_bytes());
+        }
+    }
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Serve each connection on its own thread, up to `MAX_CONNECTIONS` at once
+fn accept_connections(listener: TcpListener, token: String, calls: Sender<Call>) {
+    let open = Arc::new(AtomicUsize::new(0));
+    for stream in listener.incoming().flatten() {
+        if open.load(Ordering::SeqCst) >= MAX_CONNECTIONS {
+            continue;
+        }
+        let connection = Connection::open(&open);
+        let calls = calls.clone();
+        let token = token.clone();
+        thread::spawn(move || {
+            serve_connection(stream, &token, &calls);
+            drop(connection);
+        });
+    }
+}
+
+/// Counts a connection as open until it's dropped
+struct Connection(Arc<AtomicUsize>);
+
+impl Connection {
+    fn open(count: &Arc<AtomicUsize>) -> Self {
+        count.fetch_add(1, Ordering::SeqCst);
+        Self(Arc::clone(count))
+    }
+}
+
+impl Drop for Connection {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Answer requests on one connection until the client hangs up
+fn serve_connection(stream: TcpStream, token: &str, calls: &Sender<Call>) {
+    let Ok(reader) = stream.try_clone() else {
+        return;
+    };
+    let mut reader = BufReader::new(reader);
+    let mut writer = stream;
+    let mut line = String::new();
+    loop {
+        line.clear();
+        match (&mut reader).take(MAX_REQUEST_BYTES).read_line(&mut line) {
+            Ok(0) | Err(_) => return,
+            Ok(_) => {}
+        }
+        if !line.ends_with('\n') && line.len() as u64 >= MAX_REQUEST_BYTES {
+            let error = RpcError::new(
+                INVALID_REQUEST,
+                format!("Requests are limited to {} bytes", MAX_REQUEST_BYTES),
+            );
+            let _ = write_response(&mut writer, &error_response(Value::Null, error));
+            return;
+        }
+        if line.trim().is_empty() {
+            continue;
+        }
+        let Some(response) = answer(&line, token, calls) else {
+            continue;
+        };
+        if write_response(&mut writer, &response).is_err() {
+            return;
+        }
+    }
+}
+
+fn write_response(writer: &mut impl Write, response: &Value) -> io::Result<()> {
+    let mut bytes = response.to_string().into_bytes();
+    bytes.push(b'\n');
+    writer.write_all(&bytes)
+}
+
+/// The response line for one request, or `None` for a notification
+fn answer(line: &str, token: &str, calls: &Sender<Call>) -> Option<Value> {
+    let request: Value = match serde_json::from_str(line) {
+        Ok(request) => request,
+        Err(e) => {
+            return Some(error_response(
+                Value::Null,
+                RpcError::new(PARSE_ERROR, e.to_string()),
+            ))
+        }
+    };
+    let id = request.get("id").cloned();
+    let result = check_request(&request, token).and_then(|(method, params)| {
+        let (reply, response) = mpsc::channel();
+        calls
+            .send(Call {
+                method,
+                params,
+                reply,
+            })
+            .map_err(|_| RpcError::new(REFUSED, "The game is shutting down"))?;
+        response
+            .recv_timeout(REPLY_TIMEOUT)
+            .map_err(|_| RpcError::new(REFUSED, "The game didn't answer in time"))?
+    });
+
+    let id = id?;
+    Some(match result {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err(e) => error_response(id, e),
+    })
+}
+
+/// The method and params (without the token) of a well-formed, authorized request
+fn check_request(request: &Value, token: &str) -> Result<(String, Value), RpcError> {
+    if request.get("jsonrpc").and_then(Value::as_str) != Some("2.0") {
+        return Err(RpcError::new(
+            INVALID_REQUEST,
+            "Expected \"jsonrpc\": \"2.0\"",
+        ));
+    }
+    let Some(method) = request.get("method").and_then(Value::as_str) else {
+        return Err(RpcError::new(INVALID_REQUEST, "Missing method"));
+    };
+    let mut params = match request.get("params") {
+        None => json!({}),
+        Some(Value::Object(params)) => Value::Object(params.clone()),
+        Some(_) => return Err(RpcError::new(INVALID_PARAMS, "params must be an object")),
+    };
+    let given = params
+        .as_object_mut()
+        .and_then(|params| params.remove("token"));
+    if given.as_ref().and_then(Value::as_str) != Some(token) {
+        return Err(RpcError::new(
+            UNAUTHORIZED,
+            "Missing or wrong token; send the contents of the api-token file as params.token",
+        ));
+    }
+    Ok((method.to_string(), params))
+}
+
+fn error_response(id: Value, error: RpcError) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": error.code, "message": error.message },
+    })
+}
+
+#[derive(Deserialize)]
+struct IdParams {
+    id: u32,
+}
+
+#[derive(Deserialize)]
+struct BuyProducerParams {
+    id: u32,
+    #[serde(default = "one")]
+    quantity: u64,
+}
+
+fn one() -> u64 {
+    1
+}
+
+fn parse_params<T: for<'de> Deserialize<'de>>(params: Value) -> Result<T, RpcError> {
+    serde_json::from_value(params).map_err(|e| RpcError::new(INVALID_PARAMS, e.to_string()))
+}
+
+fn mutates(method: &str) -> bool {
+    !matches!(method, "get_state" | "get_production_breakdown")
+}
+
+fn check_read_only(app: &App, method: &str) -> Result<(), RpcError> {
+    if app.read_only && mutates(method) {
+        return Err(RpcError::new(
+            READ_ONLY,
+            format!("Save '{}' is open read-only", app.save_label),
+        ));
+    }
+    Ok(())
+}
+
+/// Run one method against the game
+pub fn dispatch(app: &mut App, method: &str, params: Value) -> Result<Value, RpcError> {
+    match method {
+        "get_state" => Ok(state(app)),
+        "get_production_breakdown" => Ok(production_breakdown(app)),
+        "buy_producer" => {
+            let BuyProducerParams { id, quantity } = parse_params(params)?;
+            let Some(producer) = Producer::all().iter().find(|p| p.id == id) else {
+                return Err(RpcError::new(INVALID_PARAMS, format!("No producer {}", id)));
+            };
+            if quantity == 0 {
+                return Err(RpcError::new(INVALID_PARAMS, "quantity must be at least 1"));
+            }
+            if !app.game.is_producer_unlocked(id) {
+                return Err(RpcError::new(
+                    REFUSED,
+                    format!("{} is locked", producer.name),
+                ));
+            }
+            if !app.game.buy_producer(id, quantity) {
+                return Err(RpcError::new(
+                    REFUSED,
+                    format!("Not enough energy for {}x {}", quantity, producer.name),
+                ));
+            }
+            Ok(json!({
+                "owned": app.game.producer_count(id),
+                "energy": app.game.energy,
+            }))
+        }
+        "buy_upgrade" => {
+            let IdParams { id } = parse_params(params)?;
+            let Some(upgrade) = Upgrade::by_id(id) else {
+                return Err(RpcError::new(INVALID_PARAMS, format!("No upgrade {}", id)));
+            };
+            if !app.game.buy_upgrade(id) {
+                return Err(RpcError::new(
+                    REFUSED,
+                    format!("{} is owned, locked, or too expensive", upgrade.name),
+                ));
+            }
+            Ok(json!({ "energy": app.game.energy }))
+        }
+        "buy_prestige_upgrade" => {
+            let IdParams { id } = parse_params(params)?;
+            let Some(upgrade) = crate::game::PrestigeUpgrade::by_id(id) else {
+                return Err(RpcError::new(
+                    INVALID_PARAMS,
+                    format!("No prestige upgrade {}", id),
+                ));
+            };
+            if !app.game.buy_prestige_upgrade(id) {
+                return Err(RpcError::new(
+                    REFUSED,
+                    format!("{} is owned, locked, or too expensive", upgrade.name),
+                ));
+            }
+            let _ = app.save();
+            Ok(json!({ "stellar_chips": app.game.stellar_chips }))
+        }
+        "ascend" => {
+            let chips_earned = app.game.calculate_potential_stellar_chips();
+            if !app.ascend() {
+                return Err(RpcError::new(
+                    REFUSED,
+                    "Ascending now would earn no Stellar Chips",
+                ));
+            }
+            Ok(json!({
+                "chips_earned": chips_earned,
+                "stellar_chips": app.game.stellar_chips,
+            }))
+        }
+        "mine" => {
+            let gained = app.game.manual_mine();
+            Ok(json!({ "energy_gained": gained, "energy": app.game.energy }))
+        }
+        _ => Err(RpcError::new(
+            METHOD_NOT_FOUND,
+            format!("Unknown method {}", method),
+        )),
+    }
+}
+
+fn state(app: &App) -> Value {
+    let game = &app.game;
+    let producers: Vec<Value> = Producer::all()
+        .iter()
+        .map(|p| {
+            let owned = game.producer_count(p.id);
+            json!({
+                "id": p.id,
+                "name": p.name,
+                "owned": owned,
+                "unlocked": game.is_producer_unlocked(p.id),
+                "cost": calculate_bulk_cost(p.base_cost, owned, 1, p.id),
+            })
+        })
+        .collect();
+    let available_upgrades: Vec<Value> = game
+        .available_upgrades()
+        .into_iter()
+        .map(|u| json!({ "id": u.id, "name": u.name, "cost": game.get_upgrade_cost(u) }))
+        .collect();
+
+    json!({
+        "label": app.save_label,
+        "read_only": app.read_only,
+        "energy": game.energy,
+        "energy_per_second": game.total_energy_per_second(),
+        "click_power": game.effective_manual_power(),
+        "total_energy_earned": game.total_energy_earned,
+        "stellar_chips": game.stellar_chips,
+        "chips_available": game.calculate_potential_stellar_chips(),
+        "ascensions": game.total_ascensions,
+        "achievements": game.achievements_unlocked.len(),
+        "time_played_secs": game.time_played_seconds(),
+        "producers": producers,
+        "upgrades_purchased": game.upgrades_purchased,
+        "available_upgrades": available_upgrades,
+        "prestige_upgrades": game.prestige_upgrades,
+    })
+}
+
+fn production_breakdown(app: &App) -> Value {
+    let game = &app.game;
+    let producers: Vec<Value> = Producer::all()
+        .iter()
+        .filter(|p| game.producer_count(p.id) > 0)
+        .map(|p| {
+            json!({
+                "id": p.id,
+                "name": p.name,
+                "owned": game.producer_count(p.id),
+                "multiplier": game.get_producer_multiplier(p.id),
+                "energy_per_second": game.producer_total_rate(p.id),
+                "percent": game.producer_production_percentage(p.id),
+            })
+        })
+        .collect();
+
+    json!({
+        "energy_per_second": game.total_energy_per_second(),
+        "global_multiplier": game.get_global_multiplier(),
+        "producers": producers,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dispatch_buys_and_refuses() {
+        let mut app = App::new("main".to_string());
+        app.game.energy = 100.0;
+
+        let bought = dispatch(&mut app, "buy_producer", json!({ "id": 1, "quantity": 2 }));
+        assert_eq!(bought.unwrap()["owned"], 2);
+
+        let refused = dispatch(
+            &mut app,
+            "buy_producer",
+            json!({ "id": 1, "quantity": 1000 }),
+        );
+        assert_eq!(refused.unwrap_err().code, REFUSED);
+        let missing = dispatch(&mut app, "buy_upgrade", json!({}));
+        assert_eq!(missing.unwrap_err().code, INVALID_PARAMS);
+        let unknown = dispatch(&mut app, "sell_everything", json!({}));
+        assert_eq!(unknown.unwrap_err().code, METHOD_NOT_FOUND);
+
+        app.read_only = true;
+        let read_only = check_read_only(&app, "mine");
+        assert_eq!(read_only.unwrap_err().code, READ_ONLY);
+        assert!(check_read_only(&app, "get_state").is_ok());
+        assert_eq!(
+            dispatch(&mut app, "get_state", json!({})).unwrap()["producers"][0]["owned"],
+            2
+        );
+    }
+
+    /// A listener on a free local port, served like the real API
+    fn local_api() -> (SocketAddr, Receiver<Call>) {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (sender, calls) = mpsc::channel();
+        thread::spawn(move || accept_connections(listener, "secret".to_string(), sender));
+        (addr, calls)
+    }
+
+    /// The next line from the server, or `None` once it hangs up
+    fn read_response(reader: &mut impl BufRead) -> Option<Value> {
+        let mut line = String::new();
+        match reader.read_line(&mut line) {
+            Ok(0) => None,
+            Ok(_) => Some(serde_json::from_str(&line).unwrap()),
+            // A reset means the server closed without reading everything sent
+            Err(_) => None,
+        }
+    }
+
+    #[test]
+    fn test_long_requests_are_refused() {
+        let (addr, _calls) = local_api();
+        let mut stream = TcpStream::connect(addr).unwrap();
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+        writeln!(stream, "{{\"id\": 1}}").unwrap();
+        let short = read_response(&mut reader).unwrap();
+        assert_eq!(short["error"]["code"], INVALID_REQUEST);
+
+        // Exactly the limit, so the server reads it all and hangs up cleanly
+        let long = vec![b' '; MAX_REQUEST_BYTES as usize];
+        stream.write_all(&long).unwrap();
+        let refused = read_response(&mut reader).unwrap();
+        assert_eq!(refused["error"]["code"], INVALID_REQUEST);
+        assert!(read_response(&mut reader).is_none());
+    }
+
+    #[test]
+    fn test_connections_are_limited() {
+        let (addr, _calls) = local_api();
+        let open: Vec<_> = (0..MAX_CONNECTIONS)
+            .map(|_| {
+                let mut stream = TcpStream::connect(addr).unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                // An answer means the connection is being served
+                writeln!(stream, "{{\"id\": 1}}").unwrap();
+                assert!(read_response(&mut reader).is_some());
+                stream
+            })
+            .collect();
+
+        let mut extra = TcpStream::connect(addr).unwrap();
+        let mut reader = BufReader::new(extra.try_clone().unwrap());
+        let _ = writeln!(extra, "{{\"id\": 1}}");
+        assert!(read_response(&mut reader).is_none());
+        drop(open);
+    }
+
+    #[test]
+    fn test_requests_need_the_token() {
+        let request = json!({ "jsonrpc": "2.0", "id": 1, "method": "get_state" });
+        assert_eq!(
+            check_request(&request, "secret").unwrap_err().code,
+            UNAUTHORIZED
+        );
+
+        let request = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "buy_upgrade",
+            "params": { "token": "secret", "id": 3 },
+        });
+        let (method, params) = check_request(&request, "secret").unwrap();
+        assert_eq!(method, "buy_upgrade");
+        assert_eq!(params, json!({ "id": 3 }));
+
+        let request = json!({ "id": 1, "method": "get_state" });
+        assert_eq!(
+            check_request(&request, "secret").unwrap_err().code,
+            INVALID_REQUEST
+        );
+    }
+}
@@ -3,12 +3,18 @@ use std::io;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::game::{GameState, PrestigeUpgrade, Producer};
-use crate::save;
+use crate::clock;
+use crate::game::{
+    offline_progress, AchievementCategory, EventKind, GameState, OfflineReport, PrestigeUpgrade,
+    Producer, QueuedPurchase,
+};
+use crate::lock::{LockError, SaveLock};
+use crate::save::{self, SaveSummary};
+use crate::settings::{self, Settings};
+use crate::theme::Theme;
 use crate::ui::animation::AnimationState;
-use crate::TICKS_PER_SECOND;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Panel {
     Producers,
     Upgrades,
@@ -16,17 +22,31 @@ pub enum Panel {
     Visualization,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum LayoutMode {
     #[default]
     Single,
     TwoColumn,
+    ThreeColumn,
+    /// Small terminals: one abbreviated panel at a time
+    Compact,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+impl LayoutMode {
+    /// Whether several panels are visible at once, so panel keys move focus
+    pub fn is_multi_column(&self) -> bool {
+        matches!(self, LayoutMode::TwoColumn | LayoutMode::ThreeColumn)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum BuyAmount {
     One,
     Ten,
+    TwentyFive,
+    Hundred,
+    Custom(u64),
+    Milestone,
     Max,
 }
 
@@ -34,21 +54,40 @@ impl BuyAmount {
     pub fn next(self) -> Self {
         match self {
             BuyAmount::One => BuyAmount::Ten,
-            BuyAmount::Ten => BuyAmount::Max,
-            BuyAmount::Max => BuyAmount::One,
+            BuyAmount::Ten => BuyAmount::TwentyFive,
+            BuyAmount::TwentyFive => BuyAmount::Hundred,
+            BuyAmount::Hundred => BuyAmount::Milestone,
+            BuyAmount::Milestone => BuyAmount::Max,
+            BuyAmount::Max | BuyAmount::Custom(_) => BuyAmount::One,
+        }
+    }
+
+    pub fn label(&self) -> String {
+        match self {
+            BuyAmount::One => "1".to_string(),
+            BuyAmount::Ten => "10".to_string(),
+            BuyAmount::TwentyFive => "25".to_string(),
+            BuyAmount::Hundred => "100".to_string(),
+            BuyAmount::Custom(n) => n.to_string(),
+            BuyAmount::Milestone => "Next".to_string(),
+            BuyAmount::Max => "Max".to_string(),
         }
     }
 
-    pub fn label(&self) -> &'static str {
+    /// The fixed quantity for this mode, if it doesn't depend on game state
+    pub fn fixed_quantity(&self) -> Option<u64> {
         match self {
-            BuyAmount::One => "1",
-            BuyAmount::Ten => "10",
-            BuyAmount::Max => "Max",
+            BuyAmount::One => Some(1),
+            BuyAmount::Ten => Some(10),
+            BuyAmount::TwentyFive => Some(25),
+            BuyAmount::Hundred => Some(100),
+            BuyAmount::Custom(n) => Some(*n),
+            BuyAmount::Milestone | BuyAmount::Max => None,
         }
     }
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct SaveData {
     pub game_state: GameState,
     pub last_save: DateTime<Utc>,
@@ -61,17 +100,42 @@ pub struct App {
     pub selected_prestige_upgrade: usize,
     pub selected_achievement: usize,
     pub achievement_tab: usize,
+    pub achievement_sort_closest: bool,
     pub active_panel: Panel,
     pub buy_amount: BuyAmount,
+    pub sell_mode: bool,
+    pub buy_amount_input: Option<String>,
     pub boss_mode: bool,
     pub offline_report: Option<OfflineReport>,
     pub layout_mode: LayoutMode,
+    /// In the two-column layout, show Stats in place of the Visualization
+    pub two_column_stats: bool,
+    pub settings: Settings,
+    pub theme: Theme,
     pub show_help: bool,
     pub show_prestige: bool,
     pub show_achievements: bool,
+    pub show_queue: bool,
+    pub selected_queue_item: usize,
+    pub show_log: bool,
+    pub log_filter: Option<EventKind>,
+    pub selected_log_entry: usize,
+    pub show_saves: bool,
+    /// Slots listed in the save manager, refreshed whenever it opens or changes
+    pub save_slots: Vec<SaveSlot>,
+    pub selected_save: usize,
+    pub save_prompt: Option<SavePrompt>,
+    /// Result of the last save manager action
+    pub save_status: Option<String>,
     pub animation: AnimationState,
+    /// Freeze star twinkle and orbits (`--no-animation`)
+    pub reduced_motion: bool,
+    /// Something on screen changed since the last frame was drawn
+    pub needs_redraw: bool,
     pub achievement_notification: Option<(String, String)>, // (name, description)
     pub achievement_notification_timer: u32,
+    /// Achievements unlocked since the popup appeared; bursts collapse into one popup
+    pub achievement_notification_count: usize,
     pub upgrade_hover_timer: u32,
     pub last_selected_upgrade: usize,
     pub show_upgrade_tooltip: bool,
@@ -82,11 +146,61 @@ pub struct App {
     pub hint_message: Option<Vec<String>>,
     pub hint_timer: u32,
     pub save_label: String,
+    /// Held while the slot is open; `None` in read-only mode
+    pub save_lock: Option<SaveLock>,
+    /// Spectator mode: the slot is open elsewhere, so nothing is saved
+    pub read_only: bool,
+    /// Set in `solaris daemon`, whose socket is named after the slot, so
+    /// clients can't switch away from it or rename it
+    pub label_pinned: bool,
+}
+
+/// What a game tick can change on screen without any input
+#[derive(PartialEq)]
+struct TickView {
+    energy: String,
+    rate: String,
+    // Durations and per-second stats update once a second
+    seconds_played: u64,
+    log_len: usize,
+    notification: Option<usize>,
+    hint: bool,
+    tooltip: bool,
+}
+
+impl TickView {
+    fn of(app: &App) -> Self {
+        Self {
+            energy: crate::format::format_energy(app.game.energy),
+            rate: crate::format::format_rate(app.game.actual_energy_per_second()),
+            seconds_played: app.game.time_played_seconds(),
+            log_len: app.game.event_log.len(),
+            notification: app
+                .achievement_notification
+                .as_ref()
+                .map(|_| app.achievement_notification_count),
+            hint: app.hint_message.is_some(),
+            tooltip: app.show_upgrade_tooltip,
+        }
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SaveSlot {
+    pub label: String,
+    /// `None` if the slot couldn't be read
+    pub summary: Option<SaveSummary>,
 }
 
-pub struct OfflineReport {
-    pub duration_secs: u64,
-    pub energy_earned: f64,
+/// Input the save manager is waiting for
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SavePrompt {
+    /// New label for a copy of the selected slot
+    Duplicate(String),
+    /// New label for the selected slot
+    Rename(String),
+    /// y/n before deleting the selected slot
+    ConfirmDelete,
 }
 
 impl App {
@@ -98,17 +212,36 @@ impl App {
             selected_prestige_upgrade: 0,
             selected_achievement: 0,
             achievement_tab: 0,
+            achievement_sort_closest: false,
             active_panel: Panel::Producers,
             buy_amount: BuyAmount::One,
+            sell_mode: false,
+            buy_amount_input: None,
             boss_mode: false,
             offline_report: None,
             layout_mode: LayoutMode::default(),
+            two_column_stats: false,
+            settings: Settings::default(),
+            theme: Theme::default(),
             show_help: false,
             show_prestige: false,
             show_achievements: false,
+            show_queue: false,
+            selected_queue_item: 0,
+            show_log: false,
+            log_filter: None,
+            selected_log_entry: 0,
+            show_saves: false,
+            save_slots: Vec::new(),
+            selected_save: 0,
+            save_prompt: None,
+            save_status: None,
             animation: AnimationState::new(),
+            reduced_motion: false,
+            needs_redraw: true,
             achievement_notification: None,
             achievement_notification_timer: 0,
+            achievement_notification_count: 0,
             upgrade_hover_timer: 0,
             last_selected_upgrade: 0,
             show_upgrade_tooltip: false,
@@ -119,6 +252,9 @@ impl App {
             hint_message: None,
             hint_timer: 0,
             save_label,
+            save_lock: None,
+            read_only: false,
+            label_pinned: false,
         }
     }
 
@@ -140,6 +276,352 @@ impl App {
         self.show_achievements = !self.show_achievements;
     }
 
+    pub fn toggle_queue(&mut self) {
+        self.show_queue = !self.show_queue;
+        self.clamp_queue_selection();
+    }
+
+    pub fn toggle_log(&mut self) {
+        self.show_log = !self.show_log;
+        self.selected_log_entry = 0;
+    }
+
+    /// Cycle the log filter: All, then each event kind
+    pub fn cycle_log_filter(&mut self) {
+        let next = match self.log_filter {
+            None => 0,
+            Some(kind) => EventKind::ALL.iter().position(|&k| k == kind).unwrap_or(0) + 1,
+        };
+        self.log_filter = EventKind::ALL.get(next).copied();
+        self.selected_log_entry = 0;
+    }
+
+    pub fn move_log_selection_down(&mut self) {
+        let max = self
+            .game
+            .event_log
+            .newest_first(self.log_filter)
+            .count()
+            .saturating_sub(1);
+        if self.selected_log_entry < max {
+            self.selected_log_entry += 1;
+        }
+    }
+
+    pub fn move_log_selection_up(&mut self) {
+        self.selected_log_entry = self.selected_log_entry.saturating_sub(1);
+    }
+
+    pub fn toggle_saves(&mut self) {
+        self.show_saves = !self.show_saves;
+        self.save_prompt = None;
+        self.save_status = None;
+        if self.show_saves {
+            self.refresh_save_slots();
+            self.selected_save = self
+                .save_slots
+                .iter()
+                .position(|slot| slot.label == self.save_label)
+                .unwrap_or(0);
+        }
+    }
+
+    /// Re-read every slot's summary. The open slot shows the live game, not its last save.
+    fn refresh_save_slots(&mut self) {
+        let saves = match save::list_saves() {
+            Ok(saves) => saves,
+            Err(e) => {
+                self.save_status = Some(format!("Could not list saves: {}", e));
+                Vec::new()
+            }
+        };
+        self.save_slots = saves
+            .into_iter()
+            .map(|info| {
+                let summary = if info.label == self.save_label {
+                    Some(self.live_summary())
+                } else {
+                    save::peek_save(&info.label).ok().flatten()
+                };
+                SaveSlot {
+                    label: info.label,
+                    summary,
+                }
+            })
+            .collect();
+        self.selected_save = self
+            .selected_save
+            .min(self.save_slots.len().saturating_sub(1));
+    }
+
+    fn live_summary(&self) -> SaveSummary {
+        let save_data = SaveData {
+            game_state: self.game.clone(),
+            last_save: clock::now(),
+        };
+        SaveSummary::from_save_data(&self.save_label, &save_data)
+    }
+
+    fn selected_save_label(&self) -> Option<String> {
+        self.save_slots
+            .get(self.selected_save)
+            .map(|slot| slot.label.clone())
+    }
+
+    fn select_save(&mut self, label: &str) {
+        if let Some(i) = self.save_slots.iter().position(|s| s.label == label) {
+            self.selected_save = i;
+        }
+    }
+
+    pub fn move_save_selection_down(&mut self) {
+        if self.selected_save + 1 < self.save_slots.len() {
+            self.selected_save += 1;
+        }
+    }
+
+    pub fn move_save_selection_up(&mut self) {
+        self.selected_save = self.selected_save.saturating_sub(1);
+    }
+
+    /// Save the current slot, then load the selected one in its place
+    pub fn switch_to_selected_save(&mut self) {
+        let Some(label) = self.selected_save_label() else {
+            return;
+        };
+        if label == self.save_label {
+            self.save_status = Some(format!("Already playing '{}'", label));
+            return;
+        }
+        if self.label_pinned {
+            self.save_status = Some(format!(
+                "The daemon runs '{}'; stop it to play another slot",
+                self.save_label
+            ));
+            return;
+        }
+        if let Err(e) = self.save() {
+            self.save_status = Some(format!("Could not save '{}': {}", self.save_label, e));
+            return;
+        }
+        let lock = match SaveLock::acquire(&label) {
+            Ok(lock) => lock,
+            Err(LockError::Held(owner)) => {
+                self.save_status = Some(format!(
+                    "'{}' is open in another instance ({})",
+                    label, owner
+                ));
+                return;
+            }
+            Err(LockError::Io(e)) => {
+                self.save_status = Some(format!("Could not lock '{}': {}", label, e));
+                return;
+            }
+        };
+
+        let previous_label = std::mem::replace(&mut self.save_label, label.clone());
+        let previous_game = std::mem::replace(&mut self.game, GameState::new());
+        if let Err(e) = self.load() {
+            // Stay on the current slot if the new one can't be read
+            self.save_label = previous_label;
+            self.game = previous_game;
+            self.save_status = Some(format!("Could not load '{}': {}", label, e));
+            return;
+        }
+        // Replacing the old lock releases it
+        self.save_lock = Some(lock);
+        self.read_only = false;
+
+        self.reset_selections();
+        self.show_saves = false;
+        self.save_prompt = None;
+        self.save_status = None;
+    }
+
+    /// Selections and popups that belong to the previous game after switching slots
+    fn reset_selections(&mut self) {
+        self.selected_producer = 0;
+        self.selected_upgrade = 0;
+        self.selected_prestige_upgrade = 0;
+        self.selected_achievement = 0;
+        self.selected_queue_item = 0;
+        self.selected_log_entry = 0;
+        self.achievement_notification = None;
+        self.achievement_notification_timer = 0;
+        self.achievement_notification_count = 0;
+        self.hint_message = None;
+        self.hint_timer = 0;
+    }
+
+    pub fn start_duplicate_save(&mut self) {
+        if let Some(label) = self.selected_save_label() {
+            self.save_prompt = Some(SavePrompt::Duplicate(format!("{}-copy", label)));
+            self.save_status = None;
+        }
+    }
+
+    pub fn start_rename_save(&mut self) {
+        let Some(label) = self.selected_save_label() else {
+            return;
+        };
+        if self.label_pinned && label == self.save_label {
+            self.save_status = Some("Can't rename the slot the daemon runs".to_string());
+            return;
+        }
+        self.save_prompt = Some(SavePrompt::Rename(label));
+        self.save_status = None;
+    }
+
+    pub fn start_delete_save(&mut self) {
+        let Some(label) = self.selected_save_label() else {
+            return;
+        };
+        if label == self.save_label {
+            self.save_status = Some("Can't delete the slot you're playing".to_string());
+            return;
+        }
+        self.save_prompt = Some(SavePrompt::ConfirmDelete);
+        self.save_status = None;
+    }
+
+    pub fn push_save_prompt_char(&mut self, c: char) {
+        if let Some(SavePrompt::Duplicate(input) | SavePrompt::Rename(input)) =
+            &mut self.save_prompt
+        {
+            if (c.is_alphanumeric() || c == '-' || c == '_') && input.len() < 50 {
+                input.push(c);
+            }
+        }
+    }
+
+    pub fn pop_save_prompt_char(&mut self) {
+        if let Some(SavePrompt::Duplicate(input) | SavePrompt::Rename(input)) =
+            &mut self.save_prompt
+        {
+            input.pop();
+        }
+    }
+
+    pub fn cancel_save_prompt(&mut self) {
+        self.save_prompt = None;
+    }
+
+    /// Carry out the pending duplicate, rename or delete
+    pub fn confirm_save_prompt(&mut self) {
+        let (Some(prompt), Some(selected)) = (self.save_prompt.take(), self.selected_save_label())
+        else {
+            return;
+        };
+
+        let result = match prompt {
+            SavePrompt::Duplicate(input) => save::validate_label(&input)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
+                .and_then(|target| {
+                    // Copy what's on screen, not the last autosave
+                    if selected == self.save_label {
+                        self.save()?;
+                    }
+                    save::duplicate_save(&selected, &target)?;
+                    Ok((
+                        target.clone(),
+                        format!("Copied '{}' to '{}'", selected, target),
+                    ))
+                }),
+            SavePrompt::Rename(input) => save::validate_label(&input)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
+                .and_then(|target| {
+                    if selected != self.save_label {
+                        save::rename_save(&selected, &target)?;
+                    } else {
+                        // The lock moves with the slot
+                        let lock = SaveLock::acquire(&target).map_err(|e| match e {
+                            LockError::Held(owner) => crate::lock::held_error(&target, &owner),
+                            LockError::Io(e) => e,
+                        })?;
+                        save::rename_save(&selected, &target)?;
+                        self.save_label = target.clone();
+                        if self.save_lock.is_some() {
+                            self.save_lock = Some(lock);
+                        }
+                    }
+                    Ok((
+                        target.clone(),
+                        format!("Renamed '{}' to '{}'", selected, target),
+                    ))
+                }),
+            SavePrompt::ConfirmDelete => save::delete_save(&selected)
+                .map(|_| (self.save_label.clone(), format!("Deleted '{}'", selected))),
+        };
+
+        match result {
+            Ok((select, message)) => {
+                self.refresh_save_slots();
+                self.select_save(&select);
+                self.save_status = Some(message);
+            }
+            Err(e) => self.save_status = Some(e.to_string()),
+        }
+    }
+
+    fn clamp_queue_selection(&mut self) {
+        let max = self.game.purchase_queue.len().saturating_sub(1);
+        self.selected_queue_item = self.selected_queue_item.min(max);
+    }
+
+    /// Add the selected producer (at the current buy amount) or upgrade to the purchase queue
+    pub fn enqueue_selected(&mut self) {
+        let item = match self.active_panel {
+            Panel::Producers => {
+                let visible = self.game.visible_producers();
+                match visible.get(self.selected_producer) {
+                    Some((_, producer)) => QueuedPurchase::Producer {
+                        id: producer.id,
+                        quantity: self.get_display_quantity_for_producer(producer),
+                    },
+                    None => return,
+                }
+            }
+            Panel::Upgrades => {
+                let available = self.game.available_upgrades();
+                match available.get(self.selected_upgrade) {
+                    Some(upgrade) => QueuedPurchase::Upgrade { id: upgrade.id },
+                    None => return,
+                }
+            }
+            _ => return,
+        };
+
+        self.game.enqueue_purchase(item);
+    }
+
+    pub fn move_queue_selection_up(&mut self) {
+        if self.selected_queue_item > 0 {
+            self.selected_queue_item -= 1;
+        }
+    }
+
+    pub fn move_queue_selection_down(&mut self) {
+        let max = self.game.purchase_queue.len().saturating_sub(1);
+        if self.selected_queue_item < max {
+            self.selected_queue_item += 1;
+        }
+    }
+
+    pub fn move_queued_item_up(&mut self) {
+        self.selected_queue_item = self.game.move_queued_purchase_up(self.selected_queue_item);
+    }
+
+    pub fn move_queued_item_down(&mut self) {
+        self.selected_queue_item = self
+            .game
+            .move_queued_purchase_down(self.selected_queue_item);
+    }
+
+    pub fn remove_selected_queue_item(&mut self) {
+        self.game.remove_queued_purchase(self.selected_queue_item);
+        self.clamp_queue_selection();
+    }
+
     pub fn toggle_producer_detail(&mut self) {
         self.show_producer_detail = !self.show_producer_detail;
         if self.show_achievements {
@@ -147,17 +629,52 @@ impl App {
         }
     }
 
+    /// Number of achievement tabs: "All" plus one per category
+    pub fn achievement_tab_count() -> usize {
+        AchievementCategory::ALL.len() + 1
+    }
+
+    /// The category shown on the current achievement tab, or None for "All"
+    pub fn achievement_tab_category(&self) -> Option<AchievementCategory> {
+        self.achievement_tab
+            .checked_sub(1)
+            .and_then(|i| AchievementCategory::ALL.get(i).copied())
+    }
+
     pub fn cycle_achievement_tab(&mut self) {
-        self.achievement_tab = (self.achievement_tab + 1) % 8;
-        self.selected_achievement = 0; // Reset selection when changing tabs
+        self.select_achievement_tab((self.achievement_tab + 1) % Self::achievement_tab_count());
+    }
+
+    /// Toggle between definition order and "closest to completion first"
+    pub fn toggle_achievement_sort(&mut self) {
+        self.achievement_sort_closest = !self.achievement_sort_closest;
+        self.selected_achievement = 0;
+    }
+
+    pub fn select_achievement_tab(&mut self, tab: usize) {
+        if tab < Self::achievement_tab_count() {
+            self.achievement_tab = tab;
+            self.selected_achievement = 0; // Reset selection when changing tabs
+        }
     }
 
     pub fn tick(&mut self) {
+        let before = TickView::of(self);
         self.game.tick();
-        self.animation.tick();
+        clock::tick();
+        if !self.reduced_motion {
+            self.animation.tick();
+        }
+        self.clamp_queue_selection();
 
-        // Check for new achievements and show notification
-        if let Some(achievement) = self.game.pop_new_achievement() {
+        // Show new achievements. Unlocks that arrive while the popup is up join it
+        // ("+5 achievements") instead of queueing; the event log keeps every one.
+        let mut latest = None;
+        while let Some(achievement) = self.game.pop_new_achievement() {
+            latest.get_or_insert(achievement);
+            self.achievement_notification_count += 1;
+        }
+        if let Some(achievement) = latest {
             self.achievement_notification = Some((
                 achievement.name.to_string(),
                 achievement.description.to_string(),
@@ -170,6 +687,7 @@ impl App {
             self.achievement_notification_timer -= 1;
             if self.achievement_notification_timer == 0 {
                 self.achievement_notification = None;
+                self.achievement_notification_count = 0;
             }
         }
 
@@ -186,14 +704,22 @@ impl App {
             self.upgrade_hover_timer = 0;
             self.show_upgrade_tooltip = false;
             self.last_selected_upgrade = self.selected_upgrade;
-        } else if self.active_panel == Panel::Upgrades && !self.show_prestige && !self.show_help {
-            if self.upgrade_hover_timer < 30 {
-                self.upgrade_hover_timer += 1;
-                if self.upgrade_hover_timer >= 30 {
-                    self.show_upgrade_tooltip = true;
-                }
+        } else if self.active_panel == Panel::Upgrades
+            && !self.show_prestige
+            && !self.show_help
+            && self.upgrade_hover_timer < 30
+        {
+            self.upgrade_hover_timer += 1;
+            if self.upgrade_hover_timer >= 30 {
+                self.show_upgrade_tooltip = true;
             }
         }
+
+        // A moving star field needs every tick drawn; otherwise only draw
+        // when the numbers, popups or once-a-second timers changed
+        if !self.reduced_motion || TickView::of(self) != before {
+            self.needs_redraw = true;
+        }
     }
 
     pub fn toggle_panel(&mut self, panel: Panel) {
@@ -212,36 +738,146 @@ impl App {
         self.show_upgrade_tooltip = false;
     }
 
+    /// Panels that can take focus in the current layout, in left-to-right order
+    fn focus_order(&self) -> &'static [Panel] {
+        match self.layout_mode {
+            LayoutMode::Single => &[],
+            // h/l are the compact layout's panel switcher
+            LayoutMode::Compact => &[Panel::Producers, Panel::Upgrades, Panel::Stats],
+            LayoutMode::TwoColumn if self.two_column_stats => {
+                &[Panel::Producers, Panel::Stats, Panel::Upgrades]
+            }
+            LayoutMode::TwoColumn => &[Panel::Producers, Panel::Visualization, Panel::Upgrades],
+            LayoutMode::ThreeColumn => &[
+                Panel::Producers,
+                Panel::Visualization,
+                Panel::Upgrades,
+                Panel::Stats,
+            ],
+        }
+    }
+
     pub fn cycle_focus_right(&mut self) {
-        if self.layout_mode == LayoutMode::TwoColumn {
-            self.active_panel = match self.active_panel {
-                Panel::Producers => Panel::Visualization,
-                Panel::Visualization => Panel::Upgrades,
-                Panel::Upgrades => Panel::Producers,
-                Panel::Stats => Panel::Producers,
-            };
-            self.upgrade_hover_timer = 0;
-            self.show_upgrade_tooltip = false;
+        let order = self.focus_order();
+        if order.is_empty() {
+            return;
         }
+        let next = match order.iter().position(|&p| p == self.active_panel) {
+            Some(i) => order[(i + 1) % order.len()],
+            None => Panel::Producers,
+        };
+        self.focus_panel(next);
     }
 
     pub fn cycle_focus_left(&mut self) {
-        if self.layout_mode == LayoutMode::TwoColumn {
-            self.active_panel = match self.active_panel {
-                Panel::Producers => Panel::Upgrades,
-                Panel::Visualization => Panel::Producers,
-                Panel::Upgrades => Panel::Visualization,
-                Panel::Stats => Panel::Producers,
-            };
-            self.upgrade_hover_timer = 0;
-            self.show_upgrade_tooltip = false;
+        let order = self.focus_order();
+        if order.is_empty() {
+            return;
+        }
+        let next = match order.iter().position(|&p| p == self.active_panel) {
+            Some(i) => order[(i + order.len() - 1) % order.len()],
+            None => Panel::Producers,
+        };
+        self.focus_panel(next);
+    }
+
+    /// Apply the layout chosen for the current terminal size, keeping the focused
+    /// panel visible when the two-column layout has to pick Stats or Visualization
+    pub fn set_layout_mode(&mut self, mode: LayoutMode) {
+        self.layout_mode = mode;
+        if mode == LayoutMode::TwoColumn {
+            match self.active_panel {
+                Panel::Stats => self.two_column_stats = true,
+                Panel::Visualization => self.two_column_stats = false,
+                _ => {}
+            }
+        }
+    }
+
+    /// `s`: toggle Stats in single-column mode, swap it with the Visualization in
+    /// two-column mode, focus it in three-column mode
+    pub fn show_stats(&mut self) {
+        match self.layout_mode {
+            LayoutMode::Single | LayoutMode::Compact => self.toggle_panel(Panel::Stats),
+            LayoutMode::TwoColumn if self.two_column_stats => {
+                self.two_column_stats = false;
+                self.focus_panel(Panel::Visualization);
+            }
+            LayoutMode::TwoColumn => {
+                self.two_column_stats = true;
+                self.focus_panel(Panel::Stats);
+            }
+            LayoutMode::ThreeColumn => self.focus_panel(Panel::Stats),
+        }
+    }
+
+    /// `v`: toggle the Visualization in single-column mode, otherwise focus it
+    pub fn show_visualization(&mut self) {
+        match self.layout_mode {
+            LayoutMode::Single | LayoutMode::Compact => self.toggle_panel(Panel::Visualization),
+            _ => {
+                self.two_column_stats = false;
+                self.focus_panel(Panel::Visualization);
+            }
         }
     }
 
+    /// Widen (positive) or narrow (negative) the Producers column and remember it
+    pub fn adjust_column_split(&mut self, delta: i16) {
+        let layout = &mut self.settings.layout;
+        layout.left_percent = layout.left_percent.saturating_add_signed(delta);
+        *layout = layout.clamped();
+        // Spectators and replays (which are read-only) keep it to themselves
+        if self.read_only {
+            return;
+        }
+        // Layout preferences are best-effort; a failed write only loses the preference
+        let _ = settings::save_settings(&self.settings);
+    }
+
     pub fn cycle_buy_amount(&mut self) {
         self.buy_amount = self.buy_amount.next();
     }
 
+    /// Open the prompt for typing a custom buy amount
+    pub fn start_custom_buy_amount(&mut self) {
+        self.buy_amount_input = Some(String::new());
+    }
+
+    pub fn push_buy_amount_digit(&mut self, digit: char) {
+        if let Some(input) = &mut self.buy_amount_input {
+            // 9 digits is plenty; anything larger can never be afforded
+            if digit.is_ascii_digit() && input.len() < 9 {
+                input.push(digit);
+            }
+        }
+    }
+
+    pub fn pop_buy_amount_digit(&mut self) {
+        if let Some(input) = &mut self.buy_amount_input {
+            input.pop();
+        }
+    }
+
+    /// Apply the typed amount; empty or zero input leaves the buy amount unchanged
+    pub fn confirm_custom_buy_amount(&mut self) {
+        if let Some(input) = self.buy_amount_input.take() {
+            if let Ok(n) = input.parse::<u64>() {
+                if n > 0 {
+                    self.buy_amount = BuyAmount::Custom(n);
+                }
+            }
+        }
+    }
+
+    pub fn cancel_custom_buy_amount(&mut self) {
+        self.buy_amount_input = None;
+    }
+
+    pub fn toggle_sell_mode(&mut self) {
+        self.sell_mode = !self.sell_mode;
+    }
+
     pub fn move_selection_up(&mut self) {
         if self.show_prestige {
             // Navigate prestige upgrades
@@ -252,17 +888,13 @@ impl App {
         }
 
         match self.active_panel {
-            Panel::Producers => {
-                if self.selected_producer > 0 {
-                    self.selected_producer -= 1;
-                }
+            Panel::Producers if self.selected_producer > 0 => {
+                self.selected_producer -= 1;
             }
-            Panel::Upgrades => {
-                if self.selected_upgrade > 0 {
-                    self.selected_upgrade -= 1;
-                    self.upgrade_hover_timer = 0;
-                    self.show_upgrade_tooltip = false;
-                }
+            Panel::Upgrades if self.selected_upgrade > 0 => {
+                self.selected_upgrade -= 1;
+                self.upgrade_hover_timer = 0;
+                self.show_upgrade_tooltip = false;
             }
             _ => {}
         }
@@ -311,6 +943,16 @@ impl App {
                 let visible = self.game.visible_producers();
                 if self.selected_producer < visible.len() {
                     let (_, producer) = visible[self.selected_producer];
+                    if self.sell_mode {
+                        let quantity = self.calculate_sell_quantity(producer);
+                        if quantity > 0 && self.game.sell_producer(producer.id, quantity) {
+                            // Selling a top tier can hide the tier above it
+                            let max = self.game.visible_producers().len().saturating_sub(1);
+                            self.selected_producer = self.selected_producer.min(max);
+                            let _ = self.save(); // Save on sale
+                        }
+                        return;
+                    }
                     let quantity = self.calculate_buy_quantity(producer);
                     if quantity > 0 {
                         self.game.buy_producer(producer.id, quantity);
@@ -344,41 +986,63 @@ impl App {
             let upgrade = &all_upgrades[self.selected_prestige_upgrade];
 
             // Try to buy the upgrade
-            if self.game.is_prestige_upgrade_available(upgrade) {
-                if self.game.buy_prestige_upgrade(upgrade.id) {
-                    let _ = self.save();
-                    return;
-                }
+            if self.game.is_prestige_upgrade_available(upgrade)
+                && self.game.buy_prestige_upgrade(upgrade.id)
+            {
+                let _ = self.save();
+                return;
             }
         }
 
         // If no upgrade purchased and at the top, try to ascend
-        if self.selected_prestige_upgrade == 0 && self.game.can_ascend() {
-            self.game.perform_ascension();
-            self.selected_producer = 0;
-            self.selected_upgrade = 0;
-            let _ = self.save();
+        if self.selected_prestige_upgrade == 0 {
+            self.ascend();
+        }
+    }
+
+    /// Ascend and save, if it would earn any Stellar Chips
+    pub fn ascend(&mut self) -> bool {
+        if !self.game.can_ascend() {
+            return false;
         }
+        self.game.perform_ascension();
+        self.selected_producer = 0;
+        self.selected_upgrade = 0;
+        let _ = self.save();
+        true
     }
 
     fn calculate_buy_quantity(&self, producer: &Producer) -> u64 {
         let owned = self.game.producer_count(producer.id);
-        match self.buy_amount {
-            BuyAmount::One => {
-                if self.game.can_afford_producer(producer, owned, 1) {
-                    1
-                } else {
-                    0
-                }
-            }
-            BuyAmount::Ten => {
-                if self.game.can_afford_producer(producer, owned, 10) {
-                    10
-                } else {
-                    0 // Can't afford 10, so can't buy anything
-                }
-            }
-            BuyAmount::Max => self.game.max_affordable(producer, owned, u64::MAX),
+        let quantity = match self.buy_amount {
+            BuyAmount::Max => return self.game.max_affordable(producer, owned, u64::MAX),
+            BuyAmount::Milestone => match self.game.next_producer_milestone(producer.id) {
+                Some(target) => target - owned,
+                None => return 0,
+            },
+            amount => amount.fixed_quantity().unwrap_or(1),
+        };
+
+        if self.game.can_afford_producer(producer, owned, quantity) {
+            quantity
+        } else {
+            0 // Can't afford the full amount, so can't buy anything
+        }
+    }
+
+    /// Number of units the current buy amount would sell (0 if not enough owned)
+    pub fn calculate_sell_quantity(&self, producer: &Producer) -> u64 {
+        let owned = self.game.producer_count(producer.id);
+        let quantity = match self.buy_amount {
+            BuyAmount::Max => owned,
+            // Milestones only make sense when buying
+            BuyAmount::Milestone => return 0,
+            amount => amount.fixed_quantity().unwrap_or(1),
+        };
+        if quantity <= owned {
+            quantity
+        } else {
+            0
         }
     }
 
@@ -391,50 +1055,89 @@ impl App {
     pub fn get_display_quantity_for_producer(&self, producer: &Producer) -> u64 {
         let owned = self.game.producer_count(producer.id);
         match self.buy_amount {
-            BuyAmount::One => 1,
-            BuyAmount::Ten => 10,
             BuyAmount::Max => self.game.max_affordable(producer, owned, u64::MAX).max(1),
+            BuyAmount::Milestone => self
+                .game
+                .next_producer_milestone(producer.id)
+                .map_or(1, |target| target - owned),
+            amount => amount.fixed_quantity().unwrap_or(1),
         }
     }
 
     pub fn save(&self) -> io::Result<()> {
+        if self.read_only {
+            return Ok(());
+        }
+        if self.lock_lost() {
+            return Err(io::Error::new(
+                io::ErrorKind::WouldBlock,
+                format!("Another instance took over '{}'", self.save_label),
+            ));
+        }
         let save_data = SaveData {
             game_state: self.game.clone(),
-            last_save: Utc::now(),
+            last_save: clock::now(),
         };
         save::save_game(&self.save_label, &save_data)
     }
 
-    pub fn load(&mut self) -> io::Result<()> {
-        if let Some(save_data) = save::load_game(&self.save_label)? {
-            let now = Utc::now();
-            let elapsed = now.signed_duration_since(save_data.last_save);
-            let elapsed_secs = elapsed.num_seconds().max(0) as u64;
+    /// Save after a crash without touching the regular slot. Returns the
+    /// emergency slot's label, or `None` in read-only mode.
+    pub fn emergency_save(&self) -> io::Result<Option<String>> {
+        if self.read_only {
+            return Ok(None);
+        }
+        let save_data = SaveData {
+            game_state: self.game.clone(),
+            last_save: clock::now(),
+        };
+        save::write_emergency_save(&self.save_label, &save_data).map(Some)
+    }
 
-            // Calculate offline progress (capped at 8 hours)
-            let max_offline_secs = 8 * 60 * 60;
-            let capped_secs = elapsed_secs.min(max_offline_secs);
+    fn lock_lost(&self) -> bool {
+        self.save_lock.as_ref().is_some_and(|lock| !lock.is_held())
+    }
 
-            self.game = save_data.game_state;
+    /// Periodic save. If another instance took the slot over, switch to
+    /// read-only rather than overwrite its progress.
+    pub fn autosave(&mut self) {
+        if self.lock_lost() {
+            self.save_lock = None;
+            self.read_only = true;
+            self.game.event_log.push(
+                EventKind::Event,
+                "Another instance took over this save; progress is no longer saved".to_string(),
+            );
+            self.needs_redraw = true;
+            return;
+        }
+        let _ = self.save();
+    }
 
-            if capped_secs > 60 {
-                // Only show report if offline for more than a minute
-                let energy_per_tick = self.game.total_energy_per_second() / TICKS_PER_SECOND;
-                let ticks = capped_secs * TICKS_PER_SECOND as u64;
+    pub fn load(&mut self) -> io::Result<()> {
+        if let Some(save_data) = save::load_game(&self.save_label)? {
+            self.restore(save_data);
+        }
+        Ok(())
+    }
 
-                // Apply offline bonus from prestige upgrades
-                let offline_bonus = self.game.get_offline_bonus_multiplier();
-                let energy_earned = energy_per_tick * ticks as f64 * offline_bonus;
+    /// Take over a save's game, crediting the progress made since it was written
+    pub fn restore(&mut self, save_data: SaveData) {
+        self.game = save_data.game_state;
 
-                self.game.add_energy(energy_earned);
+        if let Some(report) = offline_progress(&self.game, save_data.last_save, clock::now()) {
+            self.game.add_energy(report.energy_earned);
+            self.game.event_log.push(
+                EventKind::Offline,
+                format!(
+                    "Offline for {}: +{} E",
+                    crate::format::format_duration(report.duration_secs),
+                    crate::format::format_energy(report.energy_earned)
+                ),
+            );
 
-                self.offline_report = Some(OfflineReport {
-                    duration_secs: capped_secs,
-                    energy_earned,
-                });
-            }
+            self.offline_report = Some(report);
         }
-        Ok(())
     }
 
     pub fn clear_offline_report(&mut self) {
@@ -160,6 +160,7 @@ impl AutoPlayer {
         if app.buy_amount != BuyAmount::One {
             app.buy_amount = BuyAmount::One;
         }
+        app.sell_mode = false;
 
         // Close overlays (safety)
         if app.show_prestige {
@@ -171,6 +172,15 @@ impl AutoPlayer {
         if app.show_help {
             app.toggle_help();
         }
+        if app.show_queue {
+            app.toggle_queue();
+        }
+        if app.show_log {
+            app.toggle_log();
+        }
+        if app.show_saves {
+            app.toggle_saves();
+        }
 
         // Mine every tick (10 clicks/sec), independent of the decision state machine
         app.manual_mine();
@@ -560,6 +570,7 @@ pub(crate) fn simulate_upgrade_purchase(app: &App, upgrade: &Upgrade) -> f64 {
 
     let mut simulated = app.game.clone();
     simulated.upgrades_purchased.push(upgrade.id);
+    simulated.invalidate_multipliers();
 
     let new_eps = simulated.total_energy_per_second();
     new_eps - current_eps
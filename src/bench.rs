@@ -0,0 +1,112 @@
+//! Timing benchmarks for the two hottest paths: rendering a frame (60 FPS) and the
+//! auto-player's purchase decision (every tick, one simulated `GameState` per candidate).
+//!
+//! Ignored by default; run in release mode to get meaningful numbers:
+//! `cargo test --release bench -- --ignored --nocapture`
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+
+use crate::app::App;
+use crate::auto::decide_best_action;
+use crate::game::{GameState, PrestigeUpgrade, Upgrade};
+
+const ITERATIONS: u32 = 200;
+
+/// A late-game run: every producer deep into its upgrade tiers, most upgrades bought,
+/// all prestige upgrades owned and a full achievement list.
+fn late_game_state() -> GameState {
+    let mut game = GameState::new();
+    for id in 1..=20 {
+        game.producers_owned.insert(id, 400);
+    }
+    game.energy = 1e40;
+    game.total_energy_earned = 1e40;
+    game.all_time_energy_earned = 1e42;
+    game.total_manual_clicks = 50_000;
+    game.ticks_played = 20 * 3600 * 10;
+    game.total_ascensions = 12;
+    game.stellar_chips = 500;
+    game.prestige_upgrades = PrestigeUpgrade::all().iter().map(|u| u.id).collect();
+
+    // Leave every 4th upgrade unbought so the auto-player has candidates to score
+    game.upgrades_purchased = Upgrade::all()
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| i % 4 != 0)
+        .map(|(_, u)| u.id)
+        .collect();
+
+    game.check_achievements();
+    game.new_achievements.clear();
+    game
+}
+
+fn late_game_app() -> App {
+    let mut app = App::new("bench".to_string());
+    app.game = late_game_state();
+    // Fill the production history so rate displays match a running game
+    for _ in 0..10 {
+        app.tick();
+    }
+    app
+}
+
+fn time<F: FnMut()>(mut f: F) -> Duration {
+    // Warm up caches and lazy tables before measuring
+    f();
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    start.elapsed() / ITERATIONS
+}
+
+#[test]
+#[ignore]
+fn bench_render_frame() {
+    let mut app = late_game_app();
+    let mut terminal = Terminal::new(TestBackend::new(200, 60)).unwrap();
+
+    let per_frame = time(|| {
+        terminal.draw(|f| crate::ui::render(f, &mut app)).unwrap();
+    });
+
+    println!(
+        "render_frame (200x60, late game): {:?}/frame ({} frames)",
+        per_frame, ITERATIONS
+    );
+}
+
+#[test]
+#[ignore]
+fn bench_auto_decision() {
+    let app = late_game_app();
+
+    let per_decision = time(|| {
+        black_box(decide_best_action(&app));
+    });
+
+    println!(
+        "auto decide_best_action (late game): {:?}/decision ({} decisions)",
+        per_decision, ITERATIONS
+    );
+}
+
+#[test]
+#[ignore]
+fn bench_total_energy_per_second() {
+    let app = late_game_app();
+
+    let per_call = time(|| {
+        black_box(app.game.total_energy_per_second());
+    });
+
+    println!(
+        "total_energy_per_second (late game): {:?}/call ({} calls)",
+        per_call, ITERATIONS
+    );
+}
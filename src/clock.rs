@@ -0,0 +1,82 @@
+//! Where the game gets the time of day: save timestamps, offline progress
+//! and event log entries. Normally the system clock; recordings and replays
+//! use a `TickClock` so those times depend only on how many ticks ran.
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use chrono::{DateTime, TimeDelta, Utc};
+
+pub trait Clock {
+    fn now(&self) -> DateTime<Utc>;
+
+    /// Called once per game tick
+    fn tick(&self) {}
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// Starts at a fixed time and moves forward one tick length per game tick
+pub struct TickClock {
+    start: DateTime<Utc>,
+    ticks: Cell<u64>,
+}
+
+impl TickClock {
+    pub fn new(start: DateTime<Utc>) -> Self {
+        Self {
+            start,
+            ticks: Cell::new(0),
+        }
+    }
+}
+
+impl Clock for TickClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.start + TimeDelta::milliseconds((self.ticks.get() * crate::TICK_RATE_MS) as i64)
+    }
+
+    fn tick(&self) {
+        self.ticks.set(self.ticks.get() + 1);
+    }
+}
+
+thread_local! {
+    // The game runs on one thread; API and client threads only pass messages to it
+    static CLOCK: RefCell<Rc<dyn Clock>> = RefCell::new(Rc::new(SystemClock));
+}
+
+/// Use `clock` for the rest of this thread's game
+pub fn set(clock: impl Clock + 'static) {
+    CLOCK.with(|current| *current.borrow_mut() = Rc::new(clock));
+}
+
+pub fn now() -> DateTime<Utc> {
+    CLOCK.with(|clock| clock.borrow().now())
+}
+
+pub fn tick() {
+    CLOCK.with(|clock| clock.borrow().tick());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tick_clock_follows_ticks() {
+        let start = Utc::now();
+        set(TickClock::new(start));
+        assert_eq!(now(), start);
+        for _ in 0..25 {
+            tick();
+        }
+        assert_eq!(now(), start + TimeDelta::milliseconds(2500));
+    }
+}
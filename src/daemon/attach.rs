@@ -0,0 +1,226 @@
+use std::io::{self, BufReader};
+use std::os::unix::net::UnixStream;
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crossterm::event::{self, KeyCode, KeyModifiers};
+use ratatui::prelude::*;
+
+use super::protocol::{self, ClientMessage, ServerMessage};
+use crate::app::App;
+use crate::theme::Theme;
+use crate::{glyphs, save, settings, ui};
+
+/// How often to check for snapshots between key presses
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// `solaris attach [--label X] [--read-only] [--theme NAME] [--no-animation]`
+pub fn run(args: &[String]) -> io::Result<()> {
+    let mut label: Option<String> = None;
+    let mut read_only = false;
+    let mut theme_name: Option<String> = None;
+    let mut reduced_motion = false;
+
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--label" => {
+                i += 1;
+                match args.get(i).map(|l| save::validate_label(l)) {
+                    Some(Ok(sanitized)) => label = Some(sanitized),
+                    Some(Err(e)) => {
+                        eprintln!("Error: {}", e);
+                        return Ok(());
+                    }
+                    None => {
+                        eprintln!("Error: --label requires a save label");
+                        return Ok(());
+                    }
+                }
+            }
+            "--read-only" => {
+                read_only = true;
+            }
+            "--theme" => {
+                i += 1;
+                let Some(name) = args.get(i) else {
+                    eprintln!("Error: --theme requires a name");
+                    return Ok(());
+                };
+                theme_name = Some(name.clone());
+            }
+            "--no-animation" => {
+                reduced_motion = true;
+            }
+            "--help" | "-h" => {
+                print_help();
+                return Ok(());
+            }
+            other => {
+                eprintln!("Unknown attach option: {}", other);
+                eprintln!("Use 'solaris attach --help' for usage information");
+                return Ok(());
+            }
+        }
+        i += 1;
+    }
+
+    let label = match label {
+        Some(label) => label,
+        None => save::resolve_save_label(None)?.unwrap_or_else(|| "main".to_string()),
+    };
+    let mut stream = match super::connect(&label) {
+        Ok(stream) => stream,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return Ok(());
+        }
+    };
+
+    protocol::send(&mut stream, &ClientMessage::Hello { read_only })?;
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let read_only = match protocol::receive(&mut reader)? {
+        Some(ServerMessage::Welcome {
+            read_only: granted, ..
+        }) => {
+            if granted && !read_only {
+                eprintln!("Another client controls '{}'; watching read-only.", label);
+            }
+            granted
+        }
+        Some(ServerMessage::Goodbye(reason)) => {
+            eprintln!("Error: {}", reason);
+            return Ok(());
+        }
+        _ => {
+            eprintln!("Error: The daemon for '{}' didn't answer", label);
+            return Ok(());
+        }
+    };
+
+    // Snapshots are read on their own thread so the UI loop never blocks on the socket
+    let (messages, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        while let Ok(Some(message)) = protocol::receive::<ServerMessage>(&mut reader) {
+            if messages.send(message).is_err() {
+                return;
+            }
+        }
+    });
+
+    let mut app = App::new(label.clone());
+    app.read_only = read_only;
+    app.reduced_motion = reduced_motion;
+    glyphs::set_ascii(glyphs::locale_is_ascii());
+    let theme_name = theme_name
+        .or_else(|| settings::load_settings().ok().and_then(|s| s.theme))
+        .unwrap_or_else(|| "default".to_string());
+    match Theme::load(&theme_name) {
+        Ok(theme) => app.theme = theme,
+        Err(e) => eprintln!("Warning: Could not load theme: {}", e),
+    }
+
+    let mut terminal = crate::setup_terminal()?;
+    let result = run_client(&mut terminal, &mut app, &mut stream, &receiver);
+    crate::restore_terminal()?;
+
+    match result {
+        Ok(reason) => {
+            println!("{}", reason);
+            println!(
+                "The daemon keeps running '{}'. Stop it with: solaris daemon --stop --label {}",
+                label, label
+            );
+        }
+        Err(e) => eprintln!("Error: {}", e),
+    }
+    Ok(())
+}
+
+fn print_help() {
+    println!(
+        "Usage: solaris attach [--label <label>] [--read-only] [--theme <name>] [--no-animation]"
+    );
+    println!();
+    println!("Show the game a 'solaris daemon' is running. The first client to attach");
+    println!("controls it; others watch. Quitting detaches and leaves the daemon running.");
+    println!();
+    println!("  --label <label>  Save whose daemon to attach to (default: the last used save)");
+    println!("  --read-only      Watch without sending keys");
+    println!("  --theme <name>   Color theme for this client");
+    println!("  --no-animation   Freeze the star field and orbits");
+}
+
+/// Draw snapshots and forward keys until the daemon lets go of this client.
+/// Returns the reason to show after the terminal is restored.
+fn run_client<B: Backend>(
+    terminal: &mut Terminal<B>,
+    app: &mut App,
+    stream: &mut UnixStream,
+    messages: &Receiver<ServerMessage>,
+) -> io::Result<String> {
+    let tick_interval = Duration::from_millis(crate::TICK_RATE_MS);
+    let frame_interval = Duration::from_secs(1) / crate::DEFAULT_FPS;
+    let mut last_tick = Instant::now();
+    let mut last_draw: Option<Instant> = None;
+
+    if !app.read_only {
+        let size = terminal.size()?;
+        send_resize(stream, size.width, size.height)?;
+    }
+
+    loop {
+        loop {
+            match messages.try_recv() {
+                Ok(ServerMessage::Snapshot(snapshot)) => snapshot.apply(app),
+                Ok(ServerMessage::Goodbye(reason)) => return Ok(reason),
+                Ok(ServerMessage::Welcome { .. }) => {}
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => return Ok("The daemon went away".to_string()),
+            }
+        }
+
+        if app.needs_redraw && last_draw.is_none_or(|t| t.elapsed() >= frame_interval) {
+            terminal.draw(|f| ui::render(f, app))?;
+            app.needs_redraw = false;
+            last_draw = Some(Instant::now());
+        }
+
+        if event::poll(POLL_INTERVAL)? {
+            match event::read()? {
+                event::Event::Key(key) if app.read_only => {
+                    let ctrl_c = key.code == KeyCode::Char('c')
+                        && key.modifiers.contains(KeyModifiers::CONTROL);
+                    if matches!(key.code, KeyCode::Char('q') | KeyCode::Esc) || ctrl_c {
+                        return Ok("Detached".to_string());
+                    }
+                }
+                event::Event::Key(key) => {
+                    // If the daemon is gone, the reader thread reports it next time round
+                    let _ = protocol::send(stream, &ClientMessage::Key(key));
+                }
+                event::Event::Resize(width, height) => {
+                    app.needs_redraw = true;
+                    if !app.read_only {
+                        let _ = send_resize(stream, width, height);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // Animation runs locally; the daemon sends the game
+        if last_tick.elapsed() >= tick_interval {
+            if !app.reduced_motion {
+                app.animation.tick();
+                app.needs_redraw = true;
+            }
+            last_tick = Instant::now();
+        }
+    }
+}
+
+fn send_resize(stream: &mut UnixStream, width: u16, height: u16) -> io::Result<()> {
+    protocol::send(stream, &ClientMessage::Resize { width, height })
+}
@@ -0,0 +1,422 @@
+//! `solaris daemon` runs the game loop without a terminal and owns the save.
+//! `solaris attach` connects to it over a Unix socket and draws the usual UI
+//! from the snapshots it sends.
+
+pub mod attach;
+mod protocol;
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, BufReader, Write};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::PathBuf;
+use std::sync::atomic::Ordering;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use ratatui::layout::Rect;
+
+use crate::api::ApiServer;
+use crate::app::App;
+use crate::lock::{LockError, SaveLock};
+use crate::{input, save, settings, ui};
+use protocol::{ClientMessage, ServerMessage};
+
+/// A client that stops reading for this long is dropped rather than stall the game
+const WRITE_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// What the listener and client reader threads tell the game loop
+enum Event {
+    Connected(u64, UnixStream),
+    Message(u64, ClientMessage),
+    Disconnected(u64),
+}
+
+struct Client {
+    stream: UnixStream,
+    /// Set once the client has said hello; snapshots go only to these
+    read_only: Option<bool>,
+}
+
+/// Removes the socket file when the daemon exits
+struct SocketFile(PathBuf);
+
+impl Drop for SocketFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
+    }
+}
+
+/// `solaris daemon [--label X] [--autosave N] [--api-port N] [--stop]`
+pub fn run(args: &[String]) -> io::Result<()> {
+    let mut label: Option<String> = None;
+    let mut autosave_secs: Option<u64> = None;
+    let mut api_port: Option<u16> = None;
+    let mut stop = false;
+
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--label" => {
+                i += 1;
+                match args.get(i).map(|l| save::validate_label(l)) {
+                    Some(Ok(sanitized)) => label = Some(sanitized),
+                    Some(Err(e)) => {
+                        eprintln!("Error: {}", e);
+                        return Ok(());
+                    }
+                    None => {
+                        eprintln!("Error: --label requires a save label");
+                        return Ok(());
+                    }
+                }
+            }
+            "--autosave" => {
+                i += 1;
+                match args.get(i).and_then(|v| v.parse::<u64>().ok()) {
+                    Some(v)
+                        if (crate::MIN_AUTOSAVE_SECS..=crate::MAX_AUTOSAVE_SECS).contains(&v) =>
+                    {
+                        autosave_secs = Some(v)
+                    }
+                    _ => {
+                        eprintln!(
+                            "Error: --autosave value must be between {} and {} seconds",
+                            crate::MIN_AUTOSAVE_SECS,
+                            crate::MAX_AUTOSAVE_SECS
+                        );
+                        return Ok(());
+                    }
+                }
+            }
+            "--api-port" => {
+                i += 1;
+                match args.get(i).and_then(|v| v.parse::<u16>().ok()) {
+                    Some(port) => api_port = Some(port),
+                    None => {
+                        eprintln!("Error: --api-port requires a port number (0 picks a free one)");
+                        return Ok(());
+                    }
+                }
+            }
+            "--stop" => {
+                stop = true;
+            }
+            "--help" | "-h" => {
+                print_help();
+                return Ok(());
+            }
+            other => {
+                eprintln!("Unknown daemon option: {}", other);
+                eprintln!("Use 'solaris daemon --help' for usage information");
+                return Ok(());
+            }
+        }
+        i += 1;
+    }
+
+    if stop {
+        let label = match label {
+            Some(label) => label,
+            None => save::resolve_save_label(None)?.unwrap_or_else(|| "main".to_string()),
+        };
+        return stop_daemon(&label);
+    }
+
+    // Same slot resolution as the TUI: explicit label > last used > "main"
+    let label = match label {
+        Some(label) if !save::save_exists(&label)? => {
+            eprintln!(
+                "Error: Save '{}' not found. Use --list to see available saves.",
+                label
+            );
+            return Ok(());
+        }
+        Some(label) => label,
+        None => save::resolve_save_label(None)?.unwrap_or_else(|| "main".to_string()),
+    };
+
+    let lock = match SaveLock::acquire(&label) {
+        Ok(lock) => lock,
+        Err(LockError::Held(owner)) => {
+            eprintln!(
+                "Error: Save '{}' is already open in another instance ({}).",
+                label, owner
+            );
+            return Ok(());
+        }
+        Err(LockError::Io(e)) => return Err(e),
+    };
+
+    let mut app = App::new(label.clone());
+    app.save_lock = Some(lock);
+    app.label_pinned = true;
+    // Nobody watches the daemon's own animation; clients run their own
+    app.reduced_motion = true;
+    match settings::load_settings() {
+        Ok(settings) => app.settings = settings,
+        Err(e) => eprintln!("Warning: Could not load settings: {}", e),
+    }
+    if !save::save_exists(&label)? {
+        app.save()?;
+    } else if let Err(e) = app.load() {
+        eprintln!("Error: Could not load save file: {}", e);
+        return Ok(());
+    }
+    let autosave_secs = autosave_secs
+        .or(app.settings.autosave_secs)
+        .unwrap_or(crate::DEFAULT_AUTOSAVE_SECS)
+        .clamp(crate::MIN_AUTOSAVE_SECS, crate::MAX_AUTOSAVE_SECS);
+
+    let api = match crate::start_api(api_port.or(app.settings.api_port)) {
+        Ok(api) => api,
+        Err(e) => {
+            eprintln!("Error: Could not start the API: {}", e);
+            return Ok(());
+        }
+    };
+
+    // Holding the lock means any socket file left behind is from a dead daemon
+    let socket_path = save::get_socket_path(&label)?;
+    let _ = fs::remove_file(&socket_path);
+    let listener = UnixListener::bind(&socket_path)?;
+    let socket_file = SocketFile(socket_path.clone());
+
+    // The daemon outlives its terminal, so a hangup doesn't stop it
+    let shutdown = crate::register_shutdown_signals(false)?;
+
+    let (events, receiver) = mpsc::channel();
+    thread::spawn(move || accept_clients(listener, events));
+
+    eprintln!(
+        "Solaris daemon for '{}' (pid {}) listening on {}",
+        label,
+        std::process::id(),
+        socket_path.display()
+    );
+    eprintln!("Attach with: solaris attach --label {}", label);
+
+    let mut clients = BTreeMap::new();
+    let reason = serve(
+        &mut app,
+        &receiver,
+        &mut clients,
+        Duration::from_secs(autosave_secs),
+        &shutdown,
+        api.as_ref(),
+    );
+
+    // Save and let go of the slot before saying goodbye, so whatever asked the
+    // daemon to stop can open the save (or start another daemon) right away
+    if let Err(e) = app.save() {
+        eprintln!("Error: Could not save: {}", e);
+    }
+    drop(socket_file);
+    app.save_lock = None;
+    say_goodbye(&mut clients, "The daemon was stopped");
+    eprintln!("Daemon for '{}' stopped: {}", label, reason);
+    Ok(())
+}
+
+fn print_help() {
+    println!("Usage: solaris daemon [--label <label>] [--autosave <N>] [--api-port <N>]");
+    println!("       solaris daemon --stop [--label <label>]");
+    println!();
+    println!("Run a save without a terminal so it keeps progressing. Attach to it with");
+    println!("'solaris attach'. Stop it with --stop, Ctrl+C, or SIGTERM; it saves first.");
+    println!();
+    println!("  --label <label>  Save to run (default: the last used save)");
+    println!("  --autosave <N>   Autosave every N seconds (default: 30)");
+    println!("  --api-port <N>   Serve the JSON-RPC control API on 127.0.0.1:N (default: off)");
+    println!("  --stop           Ask the daemon running the save to save and exit");
+}
+
+/// Accept connections and start a reader thread for each
+fn accept_clients(listener: UnixListener, events: Sender<Event>) {
+    for (id, stream) in (0u64..).zip(listener.incoming()) {
+        let Ok(stream) = stream else { continue };
+        let Ok(reader) = stream.try_clone() else {
+            continue;
+        };
+        let _ = stream.set_write_timeout(Some(WRITE_TIMEOUT));
+        if events.send(Event::Connected(id, stream)).is_err() {
+            return;
+        }
+
+        let events = events.clone();
+        thread::spawn(move || {
+            let mut reader = BufReader::new(reader);
+            while let Ok(Some(message)) = protocol::receive(&mut reader) {
+                if events.send(Event::Message(id, message)).is_err() {
+                    return;
+                }
+            }
+            let _ = events.send(Event::Disconnected(id));
+        });
+    }
+}
+
+/// The game loop: tick, autosave, and apply the controlling client's keys.
+/// Returns why it stopped, leaving the save and goodbyes to the caller.
+fn serve(
+    app: &mut App,
+    events: &Receiver<Event>,
+    clients: &mut BTreeMap<u64, Client>,
+    autosave_interval: Duration,
+    shutdown: &std::sync::atomic::AtomicBool,
+    api: Option<&ApiServer>,
+) -> String {
+    let tick_interval = Duration::from_millis(crate::TICK_RATE_MS);
+    let mut controller: Option<u64> = None;
+    let mut last_tick = Instant::now();
+    let mut last_save = Instant::now();
+
+    loop {
+        if shutdown.load(Ordering::Relaxed) {
+            return "signal received".to_string();
+        }
+
+        // Handle client events until the next tick is due
+        let timeout = tick_interval.saturating_sub(last_tick.elapsed());
+        match events.recv_timeout(timeout) {
+            Ok(Event::Connected(id, stream)) => {
+                clients.insert(
+                    id,
+                    Client {
+                        stream,
+                        read_only: None,
+                    },
+                );
+            }
+            Ok(Event::Message(id, message)) => match message {
+                ClientMessage::Hello { read_only } => {
+                    let read_only = read_only || controller.is_some();
+                    if !read_only {
+                        controller = Some(id);
+                    }
+                    if let Some(client) = clients.get_mut(&id) {
+                        client.read_only = Some(read_only);
+                        let welcome = ServerMessage::Welcome {
+                            label: app.save_label.clone(),
+                            read_only,
+                        };
+                        if protocol::send(&mut client.stream, &welcome).is_err() {
+                            clients.remove(&id);
+                        }
+                    }
+                    app.needs_redraw = true;
+                }
+                // `daemon --stop` sends Stop without saying hello; watchers can't
+                ClientMessage::Stop
+                    if controller == Some(id)
+                        || clients.get(&id).is_some_and(|c| c.read_only.is_none()) =>
+                {
+                    return "stopped by a client".to_string();
+                }
+                // Only the controlling client changes anything else
+                _ if controller != Some(id) => {}
+                ClientMessage::Key(key) => {
+                    app.needs_redraw = true;
+                    if input::handle_key(app, key) {
+                        // Quitting from a client detaches it; the game keeps running
+                        if let Some(mut client) = clients.remove(&id) {
+                            let goodbye = ServerMessage::Goodbye("Detached".to_string());
+                            let _ = protocol::send(&mut client.stream, &goodbye);
+                        }
+                        controller = None;
+                        let _ = app.save();
+                    }
+                }
+                ClientMessage::Stop => {}
+                ClientMessage::Resize { width, height } => {
+                    app.set_layout_mode(ui::determine_layout_mode(Rect::new(0, 0, width, height)));
+                    app.needs_redraw = true;
+                }
+            },
+            Ok(Event::Disconnected(id)) => {
+                clients.remove(&id);
+                if controller == Some(id) {
+                    controller = None;
+                }
+            }
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => return "listener closed".to_string(),
+        }
+
+        if let Some(api) = api {
+            api.handle_pending(app, |_, _| {});
+        }
+
+        // Game tick at 10 Hz
+        if last_tick.elapsed() >= tick_interval {
+            app.tick();
+            last_tick = Instant::now();
+        }
+
+        if last_save.elapsed() >= autosave_interval {
+            app.autosave();
+            last_save = Instant::now();
+        }
+
+        if app.needs_redraw {
+            broadcast(app, clients);
+            app.needs_redraw = false;
+        }
+    }
+}
+
+/// Send the current state to every client that said hello, dropping any that fail
+fn broadcast(app: &App, clients: &mut BTreeMap<u64, Client>) {
+    if clients.values().all(|client| client.read_only.is_none()) {
+        return;
+    }
+    let Ok(line) = protocol::encode_snapshot(app) else {
+        return;
+    };
+    clients
+        .retain(|_, client| client.read_only.is_none() || client.stream.write_all(&line).is_ok());
+}
+
+fn say_goodbye(clients: &mut BTreeMap<u64, Client>, reason: &str) {
+    let goodbye = ServerMessage::Goodbye(reason.to_string());
+    for client in clients.values_mut() {
+        let _ = protocol::send(&mut client.stream, &goodbye);
+    }
+    clients.clear();
+}
+
+/// Connect to the daemon running a save, with a hint if there isn't one
+fn connect(label: &str) -> io::Result<UnixStream> {
+    let path = save::get_socket_path(label)?;
+    UnixStream::connect(&path).map_err(|e| {
+        io::Error::new(
+            e.kind(),
+            format!(
+                "No daemon is running '{}' ({}). Start one with: solaris daemon --label {}",
+                label, e, label
+            ),
+        )
+    })
+}
+
+fn stop_daemon(label: &str) -> io::Result<()> {
+    let mut stream = match connect(label) {
+        Ok(stream) => stream,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return Ok(());
+        }
+    };
+    protocol::send(&mut stream, &ClientMessage::Stop)?;
+
+    // The daemon says goodbye once it has saved and released the slot
+    let mut reader = BufReader::new(stream);
+    while let Ok(Some(message)) = protocol::receive::<ServerMessage>(&mut reader) {
+        if let ServerMessage::Goodbye(_) = message {
+            break;
+        }
+    }
+    println!("Stopped the daemon for '{}'", label);
+    Ok(())
+}
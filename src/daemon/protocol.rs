@@ -0,0 +1,253 @@
+//! Messages between `solaris daemon` and attached clients: one JSON object
+//! per line over the slot's Unix socket.
+
+use std::io::{self, BufRead, Write};
+
+use crossterm::event::KeyEvent;
+use serde::{Deserialize, Serialize};
+
+use crate::app::{App, BuyAmount, LayoutMode, Panel, SavePrompt, SaveSlot};
+use crate::game::{EventKind, GameState, OfflineReport};
+use crate::settings::LayoutSettings;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub enum ClientMessage {
+    /// First message after connecting. Only one client at a time controls the
+    /// game; the others watch.
+    Hello { read_only: bool },
+    /// A key press for the daemon to handle as if typed into its own TUI
+    Key(KeyEvent),
+    /// The controlling client's terminal size, which picks the layout keys act on
+    Resize { width: u16, height: u16 },
+    /// Save and stop the daemon. Accepted from the controlling client, or as
+    /// the first message on a connection, which is how `daemon --stop` asks.
+    Stop,
+}
+
+#[derive(Serialize, Deserialize)]
+pub enum ServerMessage {
+    /// Reply to `Hello`. `read_only` is true if another client already has control.
+    Welcome { label: String, read_only: bool },
+    /// The game after something changed, sent to every client
+    Snapshot(Box<Snapshot>),
+    /// The daemon is done with this client, and why
+    Goodbye(String),
+}
+
+/// What an attached client draws: the game plus the view state the
+/// controlling client's keys move. Timers, the theme and the save lock stay
+/// with whichever side owns them.
+/// The daemon sends it with the game borrowed; clients receive their own copy.
+#[derive(Serialize, Deserialize)]
+pub struct Snapshot<G = GameState> {
+    pub game: G,
+    pub selected_producer: usize,
+    pub selected_upgrade: usize,
+    pub selected_prestige_upgrade: usize,
+    pub selected_achievement: usize,
+    pub achievement_tab: usize,
+    pub achievement_sort_closest: bool,
+    pub active_panel: Panel,
+    pub buy_amount: BuyAmount,
+    pub sell_mode: bool,
+    pub buy_amount_input: Option<String>,
+    pub boss_mode: bool,
+    pub offline_report: Option<OfflineReport>,
+    pub layout_mode: LayoutMode,
+    pub two_column_stats: bool,
+    pub layout: LayoutSettings,
+    pub show_help: bool,
+    pub show_prestige: bool,
+    pub show_achievements: bool,
+    pub show_queue: bool,
+    pub selected_queue_item: usize,
+    pub show_log: bool,
+    pub log_filter: Option<EventKind>,
+    pub selected_log_entry: usize,
+    pub show_saves: bool,
+    pub save_slots: Vec<SaveSlot>,
+    pub selected_save: usize,
+    pub save_prompt: Option<SavePrompt>,
+    pub save_status: Option<String>,
+    pub achievement_notification: Option<(String, String)>,
+    pub achievement_notification_count: usize,
+    pub show_upgrade_tooltip: bool,
+    pub show_producer_detail: bool,
+    pub auto_mode: bool,
+    pub auto_paused: bool,
+    pub auto_speed: f64,
+    pub hint_message: Option<Vec<String>>,
+    pub save_label: String,
+}
+
+impl<'a> Snapshot<&'a GameState> {
+    /// Borrows the game, which is most of the snapshot, rather than cloning it
+    pub fn of(app: &'a App) -> Self {
+        Self {
+            game: &app.game,
+            selected_producer: app.selected_producer,
+            selected_upgrade: app.selected_upgrade,
+            selected_prestige_upgrade: app.selected_prestige_upgrade,
+            selected_achievement: app.selected_achievement,
+            achievement_tab: app.achievement_tab,
+            achievement_sort_closest: app.achievement_sort_closest,
+            active_panel: app.active_panel,
+            buy_amount: app.buy_amount,
+            sell_mode: app.sell_mode,
+            buy_amount_input: app.buy_amount_input.clone(),
+            boss_mode: app.boss_mode,
+            offline_report: app.offline_report,
+            layout_mode: app.layout_mode,
+            two_column_stats: app.two_column_stats,
+            layout: app.settings.layout,
+            show_help: app.show_help,
+            show_prestige: app.show_prestige,
+            show_achievements: app.show_achievements,
+            show_queue: app.show_queue,
+            selected_queue_item: app.selected_queue_item,
+            show_log: app.show_log,
+            log_filter: app.log_filter,
+            selected_log_entry: app.selected_log_entry,
+            show_saves: app.show_saves,
+            save_slots: app.save_slots.clone(),
+            selected_save: app.selected_save,
+            save_prompt: app.save_prompt.clone(),
+            save_status: app.save_status.clone(),
+            achievement_notification: app.achievement_notification.clone(),
+            achievement_notification_count: app.achievement_notification_count,
+            show_upgrade_tooltip: app.show_upgrade_tooltip,
+            show_producer_detail: app.show_producer_detail,
+            auto_mode: app.auto_mode,
+            auto_paused: app.auto_paused,
+            auto_speed: app.auto_speed,
+            hint_message: app.hint_message.clone(),
+            save_label: app.save_label.clone(),
+        }
+    }
+
+}
+
+impl Snapshot {
+    /// Show the snapshot in a client's app, leaving its theme, animation and
+    /// read-only flag alone
+    pub fn apply(self, app: &mut App) {
+        app.game = self.game;
+        app.selected_producer = self.selected_producer;
+        app.selected_upgrade = self.selected_upgrade;
+        app.selected_prestige_upgrade = self.selected_prestige_upgrade;
+        app.selected_achievement = self.selected_achievement;
+        app.achievement_tab = self.achievement_tab;
+        app.achievement_sort_closest = self.achievement_sort_closest;
+        app.active_panel = self.active_panel;
+        app.buy_amount = self.buy_amount;
+        app.sell_mode = self.sell_mode;
+        app.buy_amount_input = self.buy_amount_input;
+        app.boss_mode = self.boss_mode;
+        app.offline_report = self.offline_report;
+        app.layout_mode = self.layout_mode;
+        app.two_column_stats = self.two_column_stats;
+        app.settings.layout = self.layout;
+        app.show_help = self.show_help;
+        app.show_prestige = self.show_prestige;
+        app.show_achievements = self.show_achievements;
+        app.show_queue = self.show_queue;
+        app.selected_queue_item = self.selected_queue_item;
+        app.show_log = self.show_log;
+        app.log_filter = self.log_filter;
+        app.selected_log_entry = self.selected_log_entry;
+        app.show_saves = self.show_saves;
+        app.save_slots = self.save_slots;
+        app.selected_save = self.selected_save;
+        app.save_prompt = self.save_prompt;
+        app.save_status = self.save_status;
+        app.achievement_notification = self.achievement_notification;
+        app.achievement_notification_count = self.achievement_notification_count;
+        app.show_upgrade_tooltip = self.show_upgrade_tooltip;
+        app.show_producer_detail = self.show_producer_detail;
+        app.auto_mode = self.auto_mode;
+        app.auto_paused = self.auto_paused;
+        app.auto_speed = self.auto_speed;
+        app.hint_message = self.hint_message;
+        app.save_label = self.save_label;
+        app.needs_redraw = true;
+    }
+}
+
+/// `ServerMessage::Snapshot` without cloning the game
+#[derive(Serialize)]
+#[serde(rename = "ServerMessage")]
+enum SnapshotRef<'a> {
+    Snapshot(Snapshot<&'a GameState>),
+}
+
+/// A snapshot line, encoded once and written to every client
+pub fn encode_snapshot(app: &App) -> io::Result<Vec<u8>> {
+    encode(&SnapshotRef::Snapshot(Snapshot::of(app)))
+}
+
+pub fn encode<T: Serialize>(message: &T) -> io::Result<Vec<u8>> {
+    let mut line =
+        serde_json::to_vec(message).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    line.push(b'\n');
+    Ok(line)
+}
+
+pub fn send<T: Serialize>(writer: &mut impl Write, message: &T) -> io::Result<()> {
+    writer.write_all(&encode(message)?)
+}
+
+/// Read the next message, or `None` once the other side hangs up
+pub fn receive<T: for<'de> Deserialize<'de>>(reader: &mut impl BufRead) -> io::Result<Option<T>> {
+    let mut line = String::new();
+    if reader.read_line(&mut line)? == 0 {
+        return Ok(None);
+    }
+    serde_json::from_str(&line)
+        .map(Some)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyModifiers};
+
+    #[test]
+    fn test_snapshot_round_trip() {
+        let mut app = App::new("main".to_string());
+        app.game.energy = 1234.5;
+        app.selected_producer = 3;
+        app.show_help = true;
+
+        let line = encode_snapshot(&app).unwrap();
+        let message: ServerMessage = receive(&mut line.as_slice()).unwrap().unwrap();
+        let ServerMessage::Snapshot(snapshot) = message else {
+            panic!("expected a snapshot");
+        };
+
+        let mut client = App::new("other".to_string());
+        client.read_only = true;
+        snapshot.apply(&mut client);
+        assert_eq!(client.game.energy, 1234.5);
+        assert_eq!(client.selected_producer, 3);
+        assert!(client.show_help);
+        assert_eq!(client.save_label, "main");
+        assert!(client.read_only);
+        assert!(client.needs_redraw);
+    }
+
+    #[test]
+    fn test_client_messages_are_lines() {
+        let key = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE);
+        let mut buffer = Vec::new();
+        send(&mut buffer, &ClientMessage::Key(key)).unwrap();
+        send(&mut buffer, &ClientMessage::Stop).unwrap();
+
+        let mut reader = buffer.as_slice();
+        let first: ClientMessage = receive(&mut reader).unwrap().unwrap();
+        assert!(matches!(first, ClientMessage::Key(k) if k == key));
+        let second: ClientMessage = receive(&mut reader).unwrap().unwrap();
+        assert!(matches!(second, ClientMessage::Stop));
+        assert!(receive::<ClientMessage>(&mut reader).unwrap().is_none());
+    }
+}
@@ -1,3 +1,7 @@
+use chrono::{DateTime, Utc};
+
+use crate::glyphs::glyphs;
+
 /// Format a number for display (incremental game style)
 /// - Below 1000: show as-is with appropriate decimal places
 /// - 1000 to 999,999: comma separators (e.g., 24,900)
@@ -150,6 +154,41 @@ pub fn format_energy(value: f64) -> String {
     }
 }
 
+/// Abbreviated energy for tight spaces such as a tmux status line:
+/// "0.4", "999", "12.3K", "4.50M", falling back to scientific notation past decillions
+pub fn format_energy_short(value: f64) -> String {
+    const SUFFIXES: &[&str] = &[
+        "", "K", "M", "B", "T", "Qa", "Qi", "Sx", "Sp", "Oc", "No", "Dc",
+    ];
+
+    if value.is_nan() || value.is_infinite() {
+        return "???".to_string();
+    }
+
+    let abs_value = value.abs();
+    if abs_value < 10.0 {
+        return format!("{:.1}", value);
+    }
+    if abs_value < 1000.0 {
+        return format!("{:.0}", value);
+    }
+
+    let tier = (abs_value.log10() / 3.0).floor() as usize;
+    if tier < SUFFIXES.len() {
+        let scaled = value / 10f64.powi((tier * 3) as i32);
+        let decimals = if scaled.abs() < 10.0 {
+            2
+        } else if scaled.abs() < 100.0 {
+            1
+        } else {
+            0
+        };
+        format!("{:.*}{}", decimals, scaled, SUFFIXES[tier])
+    } else {
+        format!("{:.2e}", value)
+    }
+}
+
 /// Format integer with comma separators (e.g., 1234567 -> "1,234,567")
 fn format_with_commas(n: i64) -> String {
     let s = n.abs().to_string();
@@ -166,9 +205,9 @@ fn format_with_commas(n: i64) -> String {
     result.chars().rev().collect()
 }
 
-/// Format a cost with "⚛" suffix
+/// Format a cost with the energy glyph ("⚛", or "E" in ASCII mode) as suffix
 pub fn format_cost(value: f64) -> String {
-    format!("{} ⚛", format_energy(value))
+    format!("{} {}", format_energy(value), glyphs().energy)
 }
 
 /// Format energy per second rate
@@ -199,6 +238,35 @@ pub fn format_duration(seconds: u64) -> String {
     }
 }
 
+/// Format how long ago a moment was ("just now", "3 hours ago")
+pub fn format_time_ago(time: DateTime<Utc>) -> String {
+    let now = Utc::now();
+    let duration = now.signed_duration_since(time);
+
+    let secs = duration.num_seconds();
+    if secs < 60 {
+        return "just now".to_string();
+    }
+
+    let mins = duration.num_minutes();
+    if mins < 60 {
+        return format!("{} minute{} ago", mins, if mins == 1 { "" } else { "s" });
+    }
+
+    let hours = duration.num_hours();
+    if hours < 24 {
+        return format!("{} hour{} ago", hours, if hours == 1 { "" } else { "s" });
+    }
+
+    let days = duration.num_days();
+    if days < 30 {
+        return format!("{} day{} ago", days, if days == 1 { "" } else { "s" });
+    }
+
+    let months = days / 30;
+    format!("{} month{} ago", months, if months == 1 { "" } else { "s" })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -269,6 +337,17 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_format_energy_short() {
+        assert_eq!(format_energy_short(0.41), "0.4");
+        assert_eq!(format_energy_short(999.4), "999");
+        assert_eq!(format_energy_short(12_345.0), "12.3K");
+        assert_eq!(format_energy_short(4.5e6), "4.50M");
+        assert_eq!(format_energy_short(678e9), "678B");
+        assert_eq!(format_energy_short(2.0e33), "2.00Dc");
+        assert_eq!(format_energy_short(1.5e40), "1.50e40");
+    }
+
     #[test]
     fn test_format_duration() {
         assert_eq!(format_duration(30), "30s");
@@ -2,7 +2,10 @@ use once_cell::sync::Lazy;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum AchievementRequirement {
-    ProducerCount { producer_id: u32, count: u64 },
+    ProducerCount {
+        producer_id: u32,
+        count: u64,
+    },
     TotalEnergyPerSecond(f64),
     TotalEnergyEarned(f64),
     TotalClicks(u64),
@@ -10,6 +13,64 @@ pub enum AchievementRequirement {
     TimePlayed(u64), // seconds
     TotalProducers(u64),
     Ascensions(u64),
+    /// Reach an E/s rate within this many seconds of starting a run
+    SpeedEnergyPerSecond {
+        rate: f64,
+        within_secs: u64,
+    },
+    /// Own this many of one producer and none of any other
+    SoleProducer {
+        producer_id: u32,
+        count: u64,
+    },
+    /// Earn this many stellar chips in a single ascension
+    ChipsInOneAscension(u64),
+    AllPrestigeUpgrades,
+    /// Click this many times in a row without a gap longer than the streak window
+    ClickStreak(u64),
+    /// Own at least this many of every producer
+    EveryProducer(u64),
+    /// Reach every built-in count bonus of every producer
+    AllCountBonuses,
+}
+
+/// Achievement categories, in the order their tabs appear in the overlay
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AchievementCategory {
+    Producers,
+    Production,
+    Lifetime,
+    Clicks,
+    Upgrades,
+    Time,
+    Prestige,
+    Challenges,
+}
+
+impl AchievementCategory {
+    pub const ALL: [AchievementCategory; 8] = [
+        AchievementCategory::Producers,
+        AchievementCategory::Production,
+        AchievementCategory::Lifetime,
+        AchievementCategory::Clicks,
+        AchievementCategory::Upgrades,
+        AchievementCategory::Time,
+        AchievementCategory::Prestige,
+        AchievementCategory::Challenges,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            AchievementCategory::Producers => "Producers",
+            AchievementCategory::Production => "Production",
+            AchievementCategory::Lifetime => "Lifetime",
+            AchievementCategory::Clicks => "Clicks",
+            AchievementCategory::Upgrades => "Upgrades",
+            AchievementCategory::Time => "Time",
+            AchievementCategory::Prestige => "Prestige",
+            AchievementCategory::Challenges => "Challenges",
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -18,18 +79,58 @@ pub struct Achievement {
     pub name: &'static str,
     pub description: &'static str,
     pub requirement: AchievementRequirement,
+    pub category: AchievementCategory,
+    /// Secret achievements show "???" instead of a hint until unlocked
+    pub hidden: bool,
 }
 
 impl Achievement {
     pub fn all() -> &'static [Achievement] {
         &ACHIEVEMENTS
     }
+
+    pub fn by_id(id: u32) -> Option<&'static Achievement> {
+        // Ids are assigned sequentially from 1
+        ACHIEVEMENTS
+            .get(id.checked_sub(1)? as usize)
+            .filter(|a| a.id == id)
+    }
+}
+
+/// Live progress towards an achievement, computed from the current game state
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AchievementProgress {
+    pub current: f64,
+    pub target: f64,
+    /// Seconds until completion at the current rate, for rate-driven requirements
+    pub eta_secs: Option<u64>,
+}
+
+impl AchievementProgress {
+    pub fn new(current: f64, target: f64) -> Self {
+        Self {
+            current,
+            target,
+            eta_secs: None,
+        }
+    }
+
+    /// Completion between 0.0 and 1.0
+    pub fn fraction(&self) -> f64 {
+        if self.target <= 0.0 {
+            return 1.0;
+        }
+        (self.current / self.target).clamp(0.0, 1.0)
+    }
 }
 
 // Achievement bonus: 0.5% per achievement (multiplicative)
 // Reduced from 1% to extend late-game playtime
 pub const ACHIEVEMENT_BONUS: f64 = 1.005;
 
+// Clicks at most this many ticks apart (1 second) continue a click streak
+pub const CLICK_STREAK_WINDOW_TICKS: u64 = 10;
+
 // Generate achievements programmatically
 static ACHIEVEMENTS: Lazy<Vec<Achievement>> = Lazy::new(|| {
     let mut achievements = Vec::new();
@@ -84,6 +185,8 @@ static ACHIEVEMENTS: Lazy<Vec<Achievement>> = Lazy::new(|| {
                 name,
                 description,
                 requirement: AchievementRequirement::ProducerCount { producer_id, count },
+                category: AchievementCategory::Producers,
+                hidden: false,
             });
             id += 1;
         }
@@ -115,6 +218,8 @@ static ACHIEVEMENTS: Lazy<Vec<Achievement>> = Lazy::new(|| {
             name,
             description,
             requirement: AchievementRequirement::TotalEnergyPerSecond(rate),
+            category: AchievementCategory::Production,
+            hidden: false,
         });
         id += 1;
     }
@@ -146,6 +251,8 @@ static ACHIEVEMENTS: Lazy<Vec<Achievement>> = Lazy::new(|| {
             name,
             description,
             requirement: AchievementRequirement::TotalEnergyEarned(amount),
+            category: AchievementCategory::Lifetime,
+            hidden: false,
         });
         id += 1;
     }
@@ -172,6 +279,8 @@ static ACHIEVEMENTS: Lazy<Vec<Achievement>> = Lazy::new(|| {
             name,
             description,
             requirement: AchievementRequirement::TotalClicks(clicks),
+            category: AchievementCategory::Clicks,
+            hidden: false,
         });
         id += 1;
     }
@@ -196,6 +305,8 @@ static ACHIEVEMENTS: Lazy<Vec<Achievement>> = Lazy::new(|| {
             name,
             description,
             requirement: AchievementRequirement::UpgradesPurchased(count),
+            category: AchievementCategory::Upgrades,
+            hidden: false,
         });
         id += 1;
     }
@@ -220,6 +331,8 @@ static ACHIEVEMENTS: Lazy<Vec<Achievement>> = Lazy::new(|| {
             name,
             description,
             requirement: AchievementRequirement::TimePlayed(secs),
+            category: AchievementCategory::Time,
+            hidden: false,
         });
         id += 1;
     }
@@ -244,6 +357,8 @@ static ACHIEVEMENTS: Lazy<Vec<Achievement>> = Lazy::new(|| {
             name,
             description,
             requirement: AchievementRequirement::TotalProducers(count),
+            category: AchievementCategory::Prestige,
+            hidden: false,
         });
         id += 1;
     }
@@ -266,6 +381,166 @@ static ACHIEVEMENTS: Lazy<Vec<Achievement>> = Lazy::new(|| {
             name,
             description,
             requirement: AchievementRequirement::Ascensions(count),
+            category: AchievementCategory::Prestige,
+            hidden: false,
+        });
+        id += 1;
+    }
+
+    // Challenge achievements: speed, restraint, prestige, streaks and completion goals
+    let challenges: [(
+        &str,
+        &str,
+        AchievementRequirement,
+        AchievementCategory,
+        bool,
+    ); 17] = [
+        (
+            "Quick Start",
+            "Reach 1.00K E/s within 5 minutes of starting a run",
+            AchievementRequirement::SpeedEnergyPerSecond {
+                rate: 1_000.0,
+                within_secs: 300,
+            },
+            AchievementCategory::Challenges,
+            false,
+        ),
+        (
+            "Speed Runner",
+            "Reach 1.00M E/s within 10 minutes of starting a run",
+            AchievementRequirement::SpeedEnergyPerSecond {
+                rate: 1_000_000.0,
+                within_secs: 600,
+            },
+            AchievementCategory::Challenges,
+            false,
+        ),
+        (
+            "Light Speed",
+            "Reach 1.00B E/s within 30 minutes of starting a run",
+            AchievementRequirement::SpeedEnergyPerSecond {
+                rate: 1_000_000_000.0,
+                within_secs: 1800,
+            },
+            AchievementCategory::Challenges,
+            true,
+        ),
+        (
+            "Purist",
+            "Own 100 Solar Panels and no other producer",
+            AchievementRequirement::SoleProducer {
+                producer_id: 1,
+                count: 100,
+            },
+            AchievementCategory::Challenges,
+            false,
+        ),
+        (
+            "Solar Monk",
+            "Own 250 Solar Panels and no other producer",
+            AchievementRequirement::SoleProducer {
+                producer_id: 1,
+                count: 250,
+            },
+            AchievementCategory::Challenges,
+            true,
+        ),
+        (
+            "Hive Mind",
+            "Own 100 Mining Drones and no other producer",
+            AchievementRequirement::SoleProducer {
+                producer_id: 2,
+                count: 100,
+            },
+            AchievementCategory::Challenges,
+            true,
+        ),
+        (
+            "Big Payday",
+            "Earn 10 Stellar Chips in one ascension",
+            AchievementRequirement::ChipsInOneAscension(10),
+            AchievementCategory::Prestige,
+            false,
+        ),
+        (
+            "Chip Fortune",
+            "Earn 100 Stellar Chips in one ascension",
+            AchievementRequirement::ChipsInOneAscension(100),
+            AchievementCategory::Prestige,
+            false,
+        ),
+        (
+            "Chip Avalanche",
+            "Earn 1,000 Stellar Chips in one ascension",
+            AchievementRequirement::ChipsInOneAscension(1_000),
+            AchievementCategory::Prestige,
+            true,
+        ),
+        (
+            "Enlightened",
+            "Own every prestige upgrade",
+            AchievementRequirement::AllPrestigeUpgrades,
+            AchievementCategory::Prestige,
+            false,
+        ),
+        (
+            "Rapid Fire",
+            "Click 50 times in a row without pausing",
+            AchievementRequirement::ClickStreak(50),
+            AchievementCategory::Clicks,
+            false,
+        ),
+        (
+            "Drum Roll",
+            "Click 200 times in a row without pausing",
+            AchievementRequirement::ClickStreak(200),
+            AchievementCategory::Clicks,
+            false,
+        ),
+        (
+            "Unbroken",
+            "Click 1,000 times in a row without pausing",
+            AchievementRequirement::ClickStreak(1_000),
+            AchievementCategory::Clicks,
+            true,
+        ),
+        (
+            "Full Spectrum",
+            "Own at least one of every producer",
+            AchievementRequirement::EveryProducer(1),
+            AchievementCategory::Challenges,
+            false,
+        ),
+        (
+            "Balanced Empire",
+            "Own 100 of every producer",
+            AchievementRequirement::EveryProducer(100),
+            AchievementCategory::Challenges,
+            false,
+        ),
+        (
+            "Perfect Harmony",
+            "Own 300 of every producer",
+            AchievementRequirement::EveryProducer(300),
+            AchievementCategory::Challenges,
+            true,
+        ),
+        (
+            "Milestone Collector",
+            "Reach every count bonus of every producer",
+            AchievementRequirement::AllCountBonuses,
+            AchievementCategory::Challenges,
+            false,
+        ),
+    ];
+    for (name, description, requirement, category, hidden) in challenges {
+        achievements.push(Achievement {
+            id,
+            name,
+            description,
+            requirement,
+            category,
+            hidden,
         });
         id += 1;
     }
@@ -0,0 +1,215 @@
+use std::sync::Arc;
+
+use super::{
+    GameState, PrestigeEffect, PrestigeUpgrade, Producer, Upgrade, UpgradeEffect, ACHIEVEMENT_BONUS,
+};
+
+/// Everything the multiplier helpers need from `upgrades_purchased`, `prestige_upgrades`
+/// and `achievements_unlocked`, resolved once instead of on every call.
+///
+/// Only values that depend solely on those three lists are folded into numbers here.
+/// Anything that also depends on producer counts, energy or time (synergies, per-building
+/// bonuses, signature mechanics) keeps its parameters and is evaluated live.
+#[derive(Debug, Clone)]
+pub struct MultiplierCache {
+    /// Flat per-producer multiplier from ProducerMultiplier/DroneNetworkType (index = id - 1)
+    pub(super) producer_flat: Vec<f64>,
+    /// DroneNetworkPerBuilding: (building_id, drones_per_bonus)
+    pub(super) drone_per_building: Vec<(u32, u64)>,
+    /// Synergy: (source_id, target_id, bonus_per_source)
+    pub(super) synergies: Vec<(u32, u32, f64)>,
+    pub(super) solar_panel_base: f64,
+    pub(super) thousand_rays: f64,
+    /// EpsPerBuilding: (producer_id, bonus_percent)
+    pub(super) eps_per_building: Vec<(u32, f64)>,
+    /// EpsPerTotalBuildings bonuses
+    pub(super) eps_per_total: Vec<f64>,
+    /// Global upgrades, achievements, Cosmic Cats and prestige, multiplied together
+    pub(super) global_static: f64,
+    pub(super) stellar_essence: f64,
+    pub(super) manual_multiplier: f64,
+    pub(super) click_eps_percent: f64,
+    pub(super) upgrade_cost_reduction: f64,
+    /// Signature mechanic effects with the producer they belong to
+    pub(super) signature_effects: Vec<(u32, UpgradeEffect)>,
+    /// Product of SignatureAmplifier multipliers (index = id - 1)
+    pub(super) signature_amplifiers: Vec<f64>,
+    /// Number of signature chain upgrades purchased
+    pub(super) signature_upgrades: usize,
+}
+
+impl MultiplierCache {
+    fn build(game: &GameState) -> Self {
+        let producer_count = Producer::all().len();
+        let mut cache = MultiplierCache {
+            producer_flat: vec![1.0; producer_count],
+            drone_per_building: Vec::new(),
+            synergies: Vec::new(),
+            solar_panel_base: 1.0,
+            thousand_rays: 0.0,
+            eps_per_building: Vec::new(),
+            eps_per_total: Vec::new(),
+            global_static: 1.0,
+            stellar_essence: 0.0,
+            manual_multiplier: 1.0,
+            click_eps_percent: 0.05, // Base 5% of E/s per click
+            upgrade_cost_reduction: 0.0,
+            signature_effects: Vec::new(),
+            signature_amplifiers: vec![1.0; producer_count],
+            signature_upgrades: 0,
+        };
+
+        let mut thousand_rays_base = 0.0;
+        let mut thousand_rays_mult = 1.0;
+        let mut essence_mult = 1.0;
+        let mut cosmic_cat_bonuses = Vec::new();
+
+        let upgrades = game
+            .upgrades_purchased
+            .iter()
+            .filter_map(|id| Upgrade::by_id(*id));
+        for upgrade in upgrades {
+            if let Some(producer_id) = upgrade.effect.signature_producer() {
+                cache.signature_upgrades += 1;
+                match upgrade.effect {
+                    UpgradeEffect::SignatureAmplifier { multiplier, .. } => {
+                        cache.signature_amplifiers[producer_id as usize - 1] *= multiplier;
+                    }
+                    effect => cache.signature_effects.push((producer_id, effect)),
+                }
+                continue;
+            }
+
+            match upgrade.effect {
+                UpgradeEffect::ProducerMultiplier {
+                    producer_id,
+                    multiplier,
+                } => cache.producer_flat[producer_id as usize - 1] *= multiplier,
+                // Drone Network upgrades: 2x Drone E/s when purchased
+                UpgradeEffect::DroneNetworkType { .. } => cache.producer_flat[1] *= 2.0,
+                UpgradeEffect::DroneNetworkPerBuilding {
+                    building_id,
+                    drones_per_bonus,
+                } => cache
+                    .drone_per_building
+                    .push((building_id, drones_per_bonus)),
+                UpgradeEffect::Synergy {
+                    source_id,
+                    target_id,
+                    bonus_per_source,
+                } => cache
+                    .synergies
+                    .push((source_id, target_id, bonus_per_source)),
+                UpgradeEffect::SolarPanelBaseMultiplier(m) => cache.solar_panel_base *= m,
+                UpgradeEffect::ThousandRays(bonus) => thousand_rays_base = bonus,
+                UpgradeEffect::ThousandRaysMultiplier(m) => thousand_rays_mult *= m,
+                UpgradeEffect::GlobalMultiplier(m) => cache.global_static *= m,
+                UpgradeEffect::EpsPerBuilding {
+                    producer_id,
+                    bonus_percent,
+                } => cache.eps_per_building.push((producer_id, bonus_percent)),
+                UpgradeEffect::EpsPerTotalBuildings(bonus) => cache.eps_per_total.push(bonus),
+                UpgradeEffect::ManualMultiplier(m) => cache.manual_multiplier *= m,
+                UpgradeEffect::ClickEpsPercent(pct) => cache.click_eps_percent += pct,
+                UpgradeEffect::StellarEssenceMultiplier(m) => essence_mult *= m,
+                UpgradeEffect::CosmicCatBonus(bonus) => cosmic_cat_bonuses.push(bonus),
+                _ => {}
+            }
+        }
+
+        cache.thousand_rays = thousand_rays_base * thousand_rays_mult;
+
+        // Stellar Essence: 4% per achievement, scaled by essence upgrades
+        let achievements = game.achievements_unlocked.len();
+        cache.stellar_essence = 0.04 * achievements as f64 * essence_mult;
+
+        // Achievement bonus
+        cache.global_static *= ACHIEVEMENT_BONUS.powi(achievements as i32);
+
+        // Each Cosmic Cat upgrade multiplies by (1 + essence * bonus)
+        for bonus in cosmic_cat_bonuses {
+            cache.global_static *= 1.0 + cache.stellar_essence * bonus;
+        }
+
+        // Prestige bonuses
+        let mut cost_reduction = 0.0;
+        let prestige = game
+            .prestige_upgrades
+            .iter()
+            .filter_map(|id| PrestigeUpgrade::by_id(*id));
+        for upgrade in prestige {
+            match upgrade.effect {
+                PrestigeEffect::ProductionMultiplier(m) => cache.global_static *= m,
+                PrestigeEffect::ProductionPerAscension(bonus) => {
+                    // Cap per-ascension bonus at 2.0x (100% bonus) to prevent late-game acceleration
+                    cache.global_static *= (1.0 + bonus * game.total_ascensions as f64).min(2.0);
+                }
+                PrestigeEffect::ProductionPerAchievement(bonus) => {
+                    cache.global_static *= 1.0 + bonus * achievements as f64;
+                }
+                PrestigeEffect::UpgradeCostReduction(r) => cost_reduction += r,
+                _ => {}
+            }
+        }
+        cache.upgrade_cost_reduction = f64::min(cost_reduction, 0.50); // Cap at 50% reduction
+
+        cache
+    }
+}
+
+impl GameState {
+    /// The multiplier cache, built on first use after an invalidation
+    pub(super) fn multipliers(&self) -> &MultiplierCache {
+        self.multiplier_cache
+            .get_or_init(|| Arc::new(MultiplierCache::build(self)))
+    }
+
+    /// Drop cached multipliers. Must be called whenever `upgrades_purchased`,
+    /// `prestige_upgrades`, `achievements_unlocked` or `total_ascensions` change.
+    pub fn invalidate_multipliers(&mut self) {
+        self.multiplier_cache.take();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_upgrade_purchase_invalidates() {
+        let mut game = GameState::new();
+        game.producers_owned.insert(2, 1);
+        game.energy = 1_000.0;
+        assert_eq!(game.get_producer_multiplier(2), 1.0);
+
+        // Reinforced Drill Bits: Mining Drones are twice as efficient
+        assert!(game.buy_upgrade(201));
+        assert_eq!(game.get_producer_multiplier(2), 2.0);
+    }
+
+    #[test]
+    fn test_ascension_invalidates() {
+        let mut game = GameState::new();
+        game.total_energy_earned = 1e15;
+        game.upgrades_purchased.push(201);
+        game.producers_owned.insert(2, 1);
+        assert_eq!(game.get_producer_multiplier(2), 2.0);
+
+        game.perform_ascension();
+        assert_eq!(game.get_producer_multiplier(2), 1.0);
+    }
+
+    #[test]
+    fn test_clones_share_cache_until_changed() {
+        let mut game = GameState::new();
+        game.producers_owned.insert(2, 1);
+        let before = game.get_producer_multiplier(2);
+
+        // The auto-player mutates a clone directly and must invalidate it
+        let mut simulated = game.clone();
+        simulated.upgrades_purchased.push(201);
+        simulated.invalidate_multipliers();
+        assert_eq!(simulated.get_producer_multiplier(2), before * 2.0);
+        assert_eq!(game.get_producer_multiplier(2), before);
+    }
+}
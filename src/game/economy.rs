@@ -11,10 +11,20 @@ pub fn calculate_bulk_cost(base_cost: f64, owned: u64, quantity: u64, producer_i
     }
 
     let r = get_cost_multiplier(producer_id);
-    let first_cost = base_cost * r.powi(owned as i32);
+    let first_cost = base_cost * r.powf(owned as f64);
 
     // Geometric series sum: a * (r^n - 1) / (r - 1)
-    first_cost * (r.powi(quantity as i32) - 1.0) / (r - 1.0)
+    first_cost * (r.powf(quantity as f64) - 1.0) / (r - 1.0)
+}
+
+/// Fraction of the purchase price refunded when selling producers
+pub const SELL_REFUND_FRACTION: f64 = 0.5;
+
+/// Calculate the refund for selling the last `quantity` of `owned` items
+/// Refunds a fraction of what those units would cost to buy back
+pub fn calculate_sell_refund(base_cost: f64, owned: u64, quantity: u64, producer_id: u32) -> f64 {
+    let quantity = quantity.min(owned);
+    calculate_bulk_cost(base_cost, owned - quantity, quantity, producer_id) * SELL_REFUND_FRACTION
 }
 
 /// Calculate maximum number of items affordable
@@ -29,17 +39,28 @@ pub fn calculate_max_affordable(
         return 0;
     }
 
-    // Binary search for maximum affordable quantity
-    let mut low = 0u64;
-    let mut high = max_quantity.min(10000); // Cap at reasonable maximum
-
     // First check if we can afford at least one
-    if calculate_bulk_cost(base_cost, owned, 1, producer_id) > energy {
+    let r = get_cost_multiplier(producer_id);
+    let first_cost = base_cost * r.powf(owned as f64);
+    if first_cost > energy {
         return 0;
     }
 
+    // Invert the geometric series to bound the search: n = log_r(E * (r - 1) / a + 1)
+    // The +1 absorbs floating-point error in the estimate
+    let estimate = ((energy * (r - 1.0) / first_cost + 1.0).ln() / r.ln()).floor();
+    let upper = if estimate.is_finite() && estimate < u32::MAX as f64 {
+        estimate as u64 + 1
+    } else {
+        u32::MAX as u64
+    };
+
+    // Binary search for maximum affordable quantity
+    let mut low = 0u64;
+    let mut high = max_quantity.min(upper);
+
     while low < high {
-        let mid = low + (high - low + 1) / 2;
+        let mid = low + (high - low).div_ceil(2);
         let cost = calculate_bulk_cost(base_cost, owned, mid, producer_id);
 
         if cost <= energy {
@@ -107,5 +128,31 @@ mod tests {
 
         // With 0 energy, can afford 0
         assert_eq!(calculate_max_affordable(base, 0, 0.0, 100, producer_id), 0);
+
+        // Large purchases are found exactly without an artificial cap
+        let energy = calculate_bulk_cost(1e-250, 0, 4_000, producer_id);
+        assert_eq!(
+            calculate_max_affordable(1e-250, 0, energy * 1.000001, u64::MAX, producer_id),
+            4_000
+        );
+    }
+
+    #[test]
+    fn test_sell_refund() {
+        let base: f64 = 15.0;
+        let producer_id = 1;
+
+        // Selling the 2nd unit refunds half of what it cost (17.25)
+        assert!((calculate_sell_refund(base, 2, 1, producer_id) - 8.625).abs() < 0.001);
+
+        // Selling everything refunds half the bulk cost from zero
+        let bulk = calculate_bulk_cost(base, 0, 2, producer_id);
+        assert!((calculate_sell_refund(base, 2, 2, producer_id) - bulk / 2.0).abs() < 0.001);
+
+        // Cannot sell more than owned
+        assert_eq!(
+            calculate_sell_refund(base, 2, 5, producer_id),
+            calculate_sell_refund(base, 2, 2, producer_id)
+        );
     }
 }
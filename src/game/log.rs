@@ -0,0 +1,160 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Entries kept in the event log; older ones are dropped first
+pub const EVENT_LOG_CAPACITY: usize = 200;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EventKind {
+    /// Producer buys and sells, upgrade purchases
+    Purchase,
+    Achievement,
+    /// Ascensions and prestige upgrades
+    Prestige,
+    Offline,
+    /// Game events such as reaching a count bonus
+    Event,
+}
+
+impl EventKind {
+    pub const ALL: [EventKind; 5] = [
+        EventKind::Purchase,
+        EventKind::Achievement,
+        EventKind::Prestige,
+        EventKind::Offline,
+        EventKind::Event,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            EventKind::Purchase => "Purchases",
+            EventKind::Achievement => "Achievements",
+            EventKind::Prestige => "Prestige",
+            EventKind::Offline => "Offline",
+            EventKind::Event => "Events",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LogEntry {
+    pub time: DateTime<Utc>,
+    pub kind: EventKind,
+    pub message: String,
+    /// How many identical entries in a row this one stands for
+    #[serde(default = "default_repeat")]
+    pub repeat: u32,
+}
+
+fn default_repeat() -> u32 {
+    1
+}
+
+/// Capped, persistent history of what happened in the game, oldest first.
+/// Entries are shared between clones until one of them writes, so the auto-player's
+/// simulated `GameState` copies don't duplicate the log.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(from = "VecDeque<LogEntry>", into = "VecDeque<LogEntry>")]
+pub struct EventLog {
+    entries: Arc<VecDeque<LogEntry>>,
+}
+
+impl From<VecDeque<LogEntry>> for EventLog {
+    fn from(entries: VecDeque<LogEntry>) -> Self {
+        Self {
+            entries: Arc::new(entries),
+        }
+    }
+}
+
+impl From<EventLog> for VecDeque<LogEntry> {
+    fn from(log: EventLog) -> Self {
+        Arc::unwrap_or_clone(log.entries)
+    }
+}
+
+impl EventLog {
+    /// Record an event. A repeat of the newest entry bumps its count instead of adding a line.
+    pub fn push(&mut self, kind: EventKind, message: String) {
+        let time = crate::clock::now();
+        let entries = Arc::make_mut(&mut self.entries);
+        if let Some(last) = entries.back_mut() {
+            if last.kind == kind && last.message == message {
+                last.repeat += 1;
+                last.time = time;
+                return;
+            }
+        }
+
+        if entries.len() >= EVENT_LOG_CAPACITY {
+            entries.pop_front();
+        }
+        entries.push_back(LogEntry {
+            time,
+            kind,
+            message,
+            repeat: 1,
+        });
+    }
+
+    /// Entries newest first, optionally limited to one kind
+    pub fn newest_first(&self, kind: Option<EventKind>) -> impl Iterator<Item = &LogEntry> {
+        self.entries
+            .iter()
+            .rev()
+            .filter(move |e| kind.is_none_or(|k| e.kind == k))
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repeats_collapse() {
+        let mut log = EventLog::default();
+        log.push(EventKind::Purchase, "Bought 1x Solar Panel".to_string());
+        log.push(EventKind::Purchase, "Bought 1x Solar Panel".to_string());
+        log.push(EventKind::Achievement, "Clicker".to_string());
+
+        let entries: Vec<_> = log.newest_first(None).collect();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1].repeat, 2);
+        assert_eq!(log.newest_first(Some(EventKind::Achievement)).count(), 1);
+    }
+
+    #[test]
+    fn test_capacity() {
+        let mut log = EventLog::default();
+        for i in 0..EVENT_LOG_CAPACITY + 10 {
+            log.push(EventKind::Event, format!("event {}", i));
+        }
+        assert_eq!(log.len(), EVENT_LOG_CAPACITY);
+        let newest = log.newest_first(None).next().unwrap();
+        assert_eq!(newest.message, format!("event {}", EVENT_LOG_CAPACITY + 9));
+    }
+
+    #[test]
+    fn test_serde_roundtrip() {
+        let mut log = EventLog::default();
+        log.push(EventKind::Offline, "Offline for 2h".to_string());
+        log.push(EventKind::Offline, "Offline for 2h".to_string());
+
+        let json = serde_json::to_string(&log).unwrap();
+        let loaded: EventLog = serde_json::from_str(&json).unwrap();
+        let entry = loaded.newest_first(None).next().unwrap();
+        assert_eq!(entry.message, "Offline for 2h");
+        assert_eq!(entry.repeat, 2);
+    }
+}
@@ -1,18 +1,30 @@
 mod achievement;
+mod cache;
 mod economy;
+mod log;
+mod offline;
 mod prestige;
 mod producer;
+mod queue;
+mod signature;
 mod tick;
 mod upgrade;
 
 pub use achievement::*;
 pub use economy::*;
+pub use log::*;
+pub use offline::*;
 pub use prestige::*;
 pub use producer::*;
+pub use queue::*;
 pub use upgrade::*;
 
 use serde::{Deserialize, Serialize};
+use std::cell::OnceCell;
 use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+
+use cache::MultiplierCache;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GameState {
@@ -27,6 +39,11 @@ pub struct GameState {
     pub manual_multiplier: f64,
     #[serde(default)]
     pub total_manual_clicks: u64,
+    // Current run of clicks, each within CLICK_STREAK_WINDOW_TICKS of the last
+    #[serde(default)]
+    pub click_streak: u64,
+    #[serde(default)]
+    pub last_click_tick: u64,
     #[serde(skip)]
     pub energy_produced_history: VecDeque<f64>,
 
@@ -43,6 +60,11 @@ pub struct GameState {
     pub total_ascensions: u64,
     #[serde(default)]
     pub prestige_upgrades: Vec<u32>,
+    // Records for challenge achievements (never reset)
+    #[serde(default)]
+    pub best_click_streak: u64,
+    #[serde(default)]
+    pub best_ascension_chips: u64,
 
     // Track newly unlocked achievements for notifications
     #[serde(skip)]
@@ -55,6 +77,24 @@ pub struct GameState {
     // Per-producer lifetime energy tracking (resets on ascension)
     #[serde(default)]
     pub producer_lifetime_energy: HashMap<u32, f64>,
+
+    // Player-authored purchase queue, executed head-first as it becomes affordable
+    #[serde(default)]
+    pub purchase_queue: Vec<QueuedPurchase>,
+
+    // Signature mechanic state (resets on ascension)
+    #[serde(default)]
+    pub fusion_uptime_ticks: u64,
+    #[serde(default)]
+    pub forge_overdrive_ticks: u64,
+
+    // Timestamped history of purchases, achievements, ascensions and events
+    #[serde(default)]
+    pub event_log: EventLog,
+
+    // Derived multipliers, rebuilt lazily after invalidate_multipliers()
+    #[serde(skip)]
+    multiplier_cache: OnceCell<Arc<MultiplierCache>>,
 }
 
 fn default_manual_click_power() -> f64 {
@@ -76,15 +116,24 @@ impl GameState {
             manual_click_power: 1.0,
             manual_multiplier: 1.0,
             total_manual_clicks: 0,
+            click_streak: 0,
+            last_click_tick: 0,
             energy_produced_history: VecDeque::with_capacity(10),
             achievements_unlocked: Vec::new(),
             stellar_chips: 0,
             total_stellar_chips_earned: 0,
             total_ascensions: 0,
             prestige_upgrades: Vec::new(),
+            best_click_streak: 0,
+            best_ascension_chips: 0,
             new_achievements: Vec::new(),
             all_time_energy_earned: 0.0,
             producer_lifetime_energy: HashMap::new(),
+            purchase_queue: Vec::new(),
+            fusion_uptime_ticks: 0,
+            forge_overdrive_ticks: 0,
+            event_log: EventLog::default(),
+            multiplier_cache: OnceCell::new(),
         }
     }
 
@@ -93,8 +142,11 @@ impl GameState {
     /// Check if a producer is unlocked
     /// A producer is unlocked if:
     /// - It's the first producer (Solar Panel)
-    /// - OR the previous producer has been purchased at least once
+    /// - OR the previous producer, or any higher tier, is owned
     /// - OR a prestige upgrade unlocks it
+    ///
+    /// Looking at higher tiers keeps owned producers visible when a lower tier
+    /// is sold down to zero.
     pub fn is_producer_unlocked(&self, producer_id: u32) -> bool {
         if producer_id == 1 {
             return true;
@@ -106,8 +158,10 @@ impl GameState {
             return true;
         }
 
-        // Check if previous producer owned
-        self.producer_count(producer_id - 1) >= 1
+        // Check if previous producer (or anything above it) is owned
+        self.producers_owned
+            .iter()
+            .any(|(&id, &count)| id >= producer_id - 1 && count >= 1)
     }
 
     /// Get the number of producers unlocked by prestige upgrades
@@ -147,6 +201,91 @@ impl GameState {
         *self.producers_owned.get(&id).unwrap_or(&0)
     }
 
+    /// The next owned count of a producer that grants a count bonus or unlocks an upgrade
+    /// or achievement. Only requirements not yet met are considered; None if every
+    /// milestone is reached.
+    pub fn next_producer_milestone(&self, producer_id: u32) -> Option<u64> {
+        let owned = self.producer_count(producer_id);
+
+        let bonus_counts = self
+            .next_count_bonus(producer_id)
+            .map(|milestone| milestone.count);
+
+        let upgrade_counts = Upgrade::all()
+            .iter()
+            .filter(|u| !self.upgrades_purchased.contains(&u.id))
+            .filter_map(|u| match u.requirement {
+                UpgradeRequirement::ProducerCount {
+                    producer_id: pid,
+                    count,
+                } if pid == producer_id => Some(count),
+                _ => None,
+            });
+
+        let achievement_counts = Achievement::all()
+            .iter()
+            .filter(|a| !self.achievements_unlocked.contains(&a.id))
+            .filter_map(|a| match a.requirement {
+                AchievementRequirement::ProducerCount {
+                    producer_id: pid,
+                    count,
+                } if pid == producer_id => Some(count),
+                _ => None,
+            });
+
+        upgrade_counts
+            .chain(achievement_counts)
+            .chain(bonus_counts)
+            .filter(|&count| count > owned)
+            .min()
+    }
+
+    /// The next built-in count bonus this producer has not reached yet
+    pub fn next_count_bonus(&self, producer_id: u32) -> Option<&'static ProducerMilestone> {
+        let owned = self.producer_count(producer_id);
+        Producer::all()
+            .iter()
+            .find(|p| p.id == producer_id)?
+            .milestones
+            .iter()
+            .find(|m| m.count > owned)
+    }
+
+    /// Built-in count bonuses this producer has reached
+    fn reached_count_bonuses(
+        &self,
+        producer_id: u32,
+    ) -> impl Iterator<Item = &'static ProducerMilestone> {
+        let owned = self.producer_count(producer_id);
+        Producer::all()
+            .iter()
+            .filter(move |p| p.id == producer_id)
+            .flat_map(|p| p.milestones.iter())
+            .filter(move |m| m.count <= owned)
+    }
+
+    /// Stacked production multiplier from this producer's reached count bonuses
+    pub fn get_count_bonus_multiplier(&self, producer_id: u32) -> f64 {
+        self.reached_count_bonuses(producer_id)
+            .map(|m| match m.effect {
+                MilestoneEffect::ProducerMultiplier(mult) => mult,
+                MilestoneEffect::GlobalMultiplier(_) => 1.0,
+            })
+            .product()
+    }
+
+    /// Global multiplier from count bonuses across all producers
+    pub fn get_count_bonus_global_multiplier(&self) -> f64 {
+        Producer::all()
+            .iter()
+            .flat_map(|p| self.reached_count_bonuses(p.id))
+            .map(|m| match m.effect {
+                MilestoneEffect::GlobalMultiplier(mult) => mult,
+                MilestoneEffect::ProducerMultiplier(_) => 1.0,
+            })
+            .product()
+    }
+
     pub fn total_producers_owned(&self) -> u64 {
         self.producers_owned.values().sum()
     }
@@ -215,12 +354,62 @@ impl GameState {
         if self.energy >= cost {
             self.energy -= cost;
             *self.producers_owned.entry(id).or_insert(0) += quantity;
+            self.event_log.push(
+                EventKind::Purchase,
+                format!("Bought {}x {}", quantity, producer.name),
+            );
+            for milestone in producer
+                .milestones
+                .iter()
+                .filter(|m| m.count > owned && m.count <= owned + quantity)
+            {
+                self.event_log.push(
+                    EventKind::Event,
+                    format!(
+                        "{} count bonus at {}: {}",
+                        producer.name,
+                        milestone.count,
+                        milestone.effect.label()
+                    ),
+                );
+            }
             true
         } else {
             false
         }
     }
 
+    /// Sell producers for a partial refund of their purchase price.
+    /// The refund is not counted as earned energy, so it can't inflate
+    /// lifetime totals or stellar chips.
+    pub fn sell_producer(&mut self, id: u32, quantity: u64) -> bool {
+        let producer = match Producer::all().iter().find(|p| p.id == id) {
+            Some(p) => p,
+            None => return false,
+        };
+
+        let owned = self.producer_count(id);
+        if quantity == 0 || quantity > owned {
+            return false;
+        }
+
+        self.energy += calculate_sell_refund(producer.base_cost, owned, quantity, id);
+        // Selling a Fusion Reactor interrupts Sustained Fusion
+        if id == 7 {
+            self.fusion_uptime_ticks = 0;
+        }
+        if owned == quantity {
+            self.producers_owned.remove(&id);
+        } else {
+            self.producers_owned.insert(id, owned - quantity);
+        }
+        self.event_log.push(
+            EventKind::Purchase,
+            format!("Sold {}x {}", quantity, producer.name),
+        );
+        true
+    }
+
     pub fn can_afford_producer(&self, producer: &Producer, owned: u64, quantity: u64) -> bool {
         let cost = calculate_bulk_cost(producer.base_cost, owned, quantity, producer.id);
         self.energy >= cost
@@ -243,7 +432,7 @@ impl GameState {
             return false;
         }
 
-        let upgrade = match Upgrade::all().iter().find(|u| u.id == id) {
+        let upgrade = match Upgrade::by_id(id) {
             Some(u) => u,
             None => return false,
         };
@@ -257,6 +446,9 @@ impl GameState {
         if self.energy >= cost {
             self.energy -= cost;
             self.upgrades_purchased.push(id);
+            self.invalidate_multipliers();
+            self.event_log
+                .push(EventKind::Purchase, format!("Upgrade: {}", upgrade.name));
             true
         } else {
             false
@@ -271,17 +463,7 @@ impl GameState {
 
     /// Get total upgrade cost reduction from prestige
     fn get_upgrade_cost_reduction(&self) -> f64 {
-        let mut reduction = 0.0;
-
-        for upgrade_id in &self.prestige_upgrades {
-            if let Some(upgrade) = PrestigeUpgrade::by_id(*upgrade_id) {
-                if let PrestigeEffect::UpgradeCostReduction(r) = upgrade.effect {
-                    reduction += r;
-                }
-            }
-        }
-
-        reduction.min(0.50) // Cap at 50% reduction
+        self.multipliers().upgrade_cost_reduction
     }
 
     pub fn is_upgrade_available(&self, upgrade: &Upgrade) -> bool {
@@ -318,40 +500,13 @@ impl GameState {
     /// Get the base multiplier for Solar Panels (producer 1) from SolarPanelBaseMultiplier upgrades
     /// The first 3 solar panel upgrades double both panel E/s AND click power
     fn get_solar_panel_base_multiplier(&self) -> f64 {
-        let mut multiplier = 1.0;
-
-        for upgrade_id in &self.upgrades_purchased {
-            if let Some(upgrade) = Upgrade::all().iter().find(|u| u.id == *upgrade_id) {
-                if let UpgradeEffect::SolarPanelBaseMultiplier(m) = upgrade.effect {
-                    multiplier *= m;
-                }
-            }
-        }
-
-        multiplier
+        self.multipliers().solar_panel_base
     }
 
     /// Get the Thousand Rays bonus (flat E/s added per non-panel building)
     /// This is the base value * all multipliers from ThousandRaysMultiplier upgrades
     pub fn get_thousand_rays_bonus(&self) -> f64 {
-        let mut base_bonus = 0.0;
-        let mut multiplier = 1.0;
-
-        for upgrade_id in &self.upgrades_purchased {
-            if let Some(upgrade) = Upgrade::all().iter().find(|u| u.id == *upgrade_id) {
-                match upgrade.effect {
-                    UpgradeEffect::ThousandRays(bonus) => {
-                        base_bonus = bonus;
-                    }
-                    UpgradeEffect::ThousandRaysMultiplier(mult) => {
-                        multiplier *= mult;
-                    }
-                    _ => {}
-                }
-            }
-        }
-
-        base_bonus * multiplier
+        self.multipliers().thousand_rays
     }
 
     /// Get the total count of non-panel buildings (for Thousand Rays calculation)
@@ -364,46 +519,33 @@ impl GameState {
     }
 
     pub fn get_producer_multiplier(&self, producer_id: u32) -> f64 {
-        let mut multiplier = 1.0;
+        let cache = self.multipliers();
 
-        // Producer-specific upgrades
-        for upgrade_id in &self.upgrades_purchased {
-            if let Some(upgrade) = Upgrade::all().iter().find(|u| u.id == *upgrade_id) {
-                match upgrade.effect {
-                    UpgradeEffect::ProducerMultiplier {
-                        producer_id: pid,
-                        multiplier: m,
-                    } => {
-                        if pid == producer_id {
-                            multiplier *= m;
-                        }
-                    }
-                    // Drone Network upgrades: 2x Drone E/s when purchased
-                    UpgradeEffect::DroneNetworkType { building_id: _ } => {
-                        if producer_id == 2 {
-                            // Mining Drone
-                            multiplier *= 2.0;
-                        }
-                    }
-                    // Drone Network Per Building: +1% drone E/s per X drones for target building
-                    UpgradeEffect::DroneNetworkPerBuilding {
-                        building_id,
-                        drones_per_bonus,
-                    } => {
-                        if producer_id == building_id {
-                            let drone_count = self.producer_count(2);
-                            let bonus_percent = drone_count as f64 / drones_per_bonus as f64;
-                            multiplier *= 1.0 + (bonus_percent / 100.0);
-                        }
-                    }
-                    _ => {}
-                }
+        // Producer-specific upgrades (and Drone Network types for Mining Drones)
+        let mut multiplier = cache
+            .producer_flat
+            .get(producer_id as usize - 1)
+            .copied()
+            .unwrap_or(1.0);
+
+        // Drone Network Per Building: +1% E/s per X drones for the target building
+        let drone_count = self.producer_count(2);
+        for &(building_id, drones_per_bonus) in &cache.drone_per_building {
+            if building_id == producer_id {
+                let bonus_percent = drone_count as f64 / drones_per_bonus as f64;
+                multiplier *= 1.0 + (bonus_percent / 100.0);
             }
         }
 
         // Synergy bonuses
         multiplier *= self.get_synergy_multiplier(producer_id);
 
+        // Built-in bonuses for owning 25/50/100/... of this producer
+        multiplier *= self.get_count_bonus_multiplier(producer_id);
+
+        // Signature mechanic (producers 3-20)
+        multiplier *= self.get_signature_multiplier(producer_id);
+
         // Special handling for Solar Panel (producer 1) - panel base multiplier
         if producer_id == 1 {
             multiplier *= self.get_solar_panel_base_multiplier();
@@ -420,43 +562,27 @@ impl GameState {
     /// Calculate Mining Drone's self-bonus from DroneNetworkPerBuilding upgrades
     /// Drones gain +1% E/s per X drones for each building type unlocked
     fn get_drone_self_bonus(&self) -> f64 {
-        let mut bonus = 1.0;
         let drone_count = self.producer_count(2);
 
-        for upgrade_id in &self.upgrades_purchased {
-            if let Some(upgrade) = Upgrade::all().iter().find(|u| u.id == *upgrade_id) {
-                if let UpgradeEffect::DroneNetworkPerBuilding {
-                    building_id: _,
-                    drones_per_bonus,
-                } = upgrade.effect
-                {
-                    // Each Drone Network upgrade also gives drones +1% per X drones
-                    let bonus_percent = drone_count as f64 / drones_per_bonus as f64;
-                    bonus *= 1.0 + (bonus_percent / 100.0);
-                }
-            }
-        }
-
-        bonus
+        self.multipliers()
+            .drone_per_building
+            .iter()
+            .map(|&(_, drones_per_bonus)| {
+                // Each Drone Network upgrade also gives drones +1% per X drones
+                let bonus_percent = drone_count as f64 / drones_per_bonus as f64;
+                1.0 + (bonus_percent / 100.0)
+            })
+            .product()
     }
 
     /// Calculate synergy bonus for a producer (capped at 2.5x)
     fn get_synergy_multiplier(&self, target_id: u32) -> f64 {
         let mut bonus = 1.0;
 
-        for upgrade_id in &self.upgrades_purchased {
-            if let Some(upgrade) = Upgrade::all().iter().find(|u| u.id == *upgrade_id) {
-                if let UpgradeEffect::Synergy {
-                    source_id,
-                    target_id: tid,
-                    bonus_per_source,
-                } = upgrade.effect
-                {
-                    if tid == target_id {
-                        let source_count = self.producer_count(source_id);
-                        bonus *= 1.0 + (bonus_per_source * source_count as f64);
-                    }
-                }
+        for &(source_id, tid, bonus_per_source) in &self.multipliers().synergies {
+            if tid == target_id {
+                let source_count = self.producer_count(source_id);
+                bonus *= 1.0 + (bonus_per_source * source_count as f64);
             }
         }
 
@@ -465,41 +591,30 @@ impl GameState {
     }
 
     pub fn get_global_multiplier(&self) -> f64 {
-        let mut multiplier = 1.0;
+        let cache = self.multipliers();
 
-        // Global upgrades and flavor upgrades
-        for upgrade_id in &self.upgrades_purchased {
-            if let Some(upgrade) = Upgrade::all().iter().find(|u| u.id == *upgrade_id) {
-                match upgrade.effect {
-                    UpgradeEffect::GlobalMultiplier(m) => {
-                        multiplier *= m;
-                    }
-                    UpgradeEffect::EpsPerBuilding {
-                        producer_id,
-                        bonus_percent,
-                    } => {
-                        // +X% E/s per building of this type owned
-                        let count = self.producer_count(producer_id);
-                        multiplier *= 1.0 + (bonus_percent * count as f64);
-                    }
-                    UpgradeEffect::EpsPerTotalBuildings(bonus_percent) => {
-                        // +X% E/s per total buildings owned
-                        let total = self.total_producers_owned();
-                        multiplier *= 1.0 + (bonus_percent * total as f64);
-                    }
-                    _ => {}
-                }
-            }
+        // Global upgrades, achievements, Cosmic Cats and prestige bonuses
+        let mut multiplier = cache.global_static;
+
+        // +X% E/s per building of this type owned
+        for &(producer_id, bonus_percent) in &cache.eps_per_building {
+            let count = self.producer_count(producer_id);
+            multiplier *= 1.0 + (bonus_percent * count as f64);
         }
 
-        // Achievement bonus (1% per achievement)
-        multiplier *= self.get_achievement_multiplier();
+        // +X% E/s per total buildings owned
+        if !cache.eps_per_total.is_empty() {
+            let total = self.total_producers_owned();
+            for bonus_percent in &cache.eps_per_total {
+                multiplier *= 1.0 + (bonus_percent * total as f64);
+            }
+        }
 
-        // Cosmic Cat bonus based on stellar essence
-        multiplier *= self.get_cosmic_cat_multiplier();
+        // Global boosts from producer count bonuses
+        multiplier *= self.get_count_bonus_global_multiplier();
 
-        // Prestige bonuses
-        multiplier *= self.get_prestige_production_multiplier();
+        // Galactic Gravity signature mechanic
+        multiplier *= self.get_signature_global_multiplier();
 
         multiplier
     }
@@ -512,64 +627,33 @@ impl GameState {
     /// Get Stellar Essence amount - 4% per achievement
     /// Range: 0.0 to theoretically unlimited (but achievements are finite)
     pub fn get_stellar_essence(&self) -> f64 {
-        let base_essence = 0.04 * self.achievements_unlocked.len() as f64;
-
-        // Apply StellarEssenceMultiplier upgrades
-        let mut multiplier = 1.0;
-        for upgrade_id in &self.upgrades_purchased {
-            if let Some(upgrade) = Upgrade::all().iter().find(|u| u.id == *upgrade_id) {
-                if let UpgradeEffect::StellarEssenceMultiplier(m) = upgrade.effect {
-                    multiplier *= m;
-                }
-            }
-        }
-
-        base_essence * multiplier
+        self.multipliers().stellar_essence
     }
 
-    /// Get Cosmic Cat multiplier based on stellar essence
-    /// Each CosmicCatBonus upgrade multiplies E/s by (1 + stellar_essence * bonus)
-    fn get_cosmic_cat_multiplier(&self) -> f64 {
-        let essence = self.get_stellar_essence();
-        let mut multiplier = 1.0;
-
-        for upgrade_id in &self.upgrades_purchased {
-            if let Some(upgrade) = Upgrade::all().iter().find(|u| u.id == *upgrade_id) {
-                if let UpgradeEffect::CosmicCatBonus(bonus) = upgrade.effect {
-                    // Each Cosmic Cat upgrade multiplies by (1 + essence * bonus)
-                    multiplier *= 1.0 + (essence * bonus);
-                }
-            }
-        }
-
-        multiplier
+    /// Unlocked and total achievements in a category
+    pub fn achievement_category_progress(&self, category: AchievementCategory) -> (usize, usize) {
+        let unlocked = self
+            .achievements_unlocked
+            .iter()
+            .filter_map(|id| Achievement::by_id(*id))
+            .filter(|a| a.category == category)
+            .count();
+        let total = Achievement::all()
+            .iter()
+            .filter(|a| a.category == category)
+            .count();
+        (unlocked, total)
     }
 
-    /// Get prestige production multiplier
-    fn get_prestige_production_multiplier(&self) -> f64 {
-        let mut multiplier = 1.0;
-
-        for upgrade_id in &self.prestige_upgrades {
-            if let Some(upgrade) = PrestigeUpgrade::by_id(*upgrade_id) {
-                match upgrade.effect {
-                    PrestigeEffect::ProductionMultiplier(m) => {
-                        multiplier *= m;
-                    }
-                    PrestigeEffect::ProductionPerAscension(bonus) => {
-                        // Cap per-ascension bonus at 2.0x (100% bonus) to prevent late-game acceleration
-                        let ascension_mult =
-                            (1.0 + (bonus * self.total_ascensions as f64)).min(2.0);
-                        multiplier *= ascension_mult;
-                    }
-                    PrestigeEffect::ProductionPerAchievement(bonus) => {
-                        multiplier *= 1.0 + (bonus * self.achievements_unlocked.len() as f64);
-                    }
-                    _ => {}
-                }
-            }
-        }
-
-        multiplier
+    /// The share of the achievement multiplier and Stellar Essence that comes from one
+    /// category: (production multiplier, essence)
+    pub fn achievement_category_bonus(&self, category: AchievementCategory) -> (f64, f64) {
+        let (unlocked, _) = self.achievement_category_progress(category);
+        let essence = match self.achievements_unlocked.len() {
+            0 => 0.0,
+            total => self.get_stellar_essence() * unlocked as f64 / total as f64,
+        };
+        (ACHIEVEMENT_BONUS.powi(unlocked as i32), essence)
     }
 
     pub fn total_energy_per_second(&self) -> f64 {
@@ -602,33 +686,13 @@ impl GameState {
     }
 
     pub fn get_manual_multiplier(&self) -> f64 {
-        let mut multiplier = 1.0;
-
-        for upgrade_id in &self.upgrades_purchased {
-            if let Some(upgrade) = Upgrade::all().iter().find(|u| u.id == *upgrade_id) {
-                if let UpgradeEffect::ManualMultiplier(m) = upgrade.effect {
-                    multiplier *= m;
-                }
-            }
-        }
-
-        multiplier
+        self.multipliers().manual_multiplier
     }
 
     /// Get the click E/s percent bonus from ClickEpsPercent upgrades
     /// Base is 5% (0.05), upgrades add to this
     fn get_click_eps_percent(&self) -> f64 {
-        let mut bonus = 0.05; // Base 5% of E/s per click
-
-        for upgrade_id in &self.upgrades_purchased {
-            if let Some(upgrade) = Upgrade::all().iter().find(|u| u.id == *upgrade_id) {
-                if let UpgradeEffect::ClickEpsPercent(pct) = upgrade.effect {
-                    bonus += pct;
-                }
-            }
-        }
-
-        bonus
+        self.multipliers().click_eps_percent
     }
 
     pub fn manual_mine(&mut self) -> f64 {
@@ -650,6 +714,7 @@ impl GameState {
         self.total_energy_earned += energy_gained;
         self.all_time_energy_earned += energy_gained;
         self.total_manual_clicks += 1;
+        self.record_click_streak();
 
         // Add to current tick's tracking for rate display
         if let Some(last) = self.energy_produced_history.back_mut() {
@@ -661,8 +726,18 @@ impl GameState {
         energy_gained
     }
 
+    fn record_click_streak(&mut self) {
+        let continues = self.click_streak > 0
+            && self.ticks_played.saturating_sub(self.last_click_tick) <= CLICK_STREAK_WINDOW_TICKS;
+        self.click_streak = if continues { self.click_streak + 1 } else { 1 };
+        self.last_click_tick = self.ticks_played;
+        self.best_click_streak = self.best_click_streak.max(self.click_streak);
+    }
+
     pub fn actual_energy_per_second(&self) -> f64 {
-        self.energy_produced_history.iter().sum()
+        // Adding 0.0 turns the empty sum (-0.0, e.g. right after loading) into 0.0,
+        // which would otherwise show as "-0.00/s"
+        self.energy_produced_history.iter().sum::<f64>() + 0.0
     }
 
     pub fn effective_manual_power(&self) -> f64 {
@@ -708,11 +783,134 @@ impl GameState {
                     self.total_producers_owned() >= count
                 }
                 AchievementRequirement::Ascensions(count) => self.total_ascensions >= count,
+                AchievementRequirement::SpeedEnergyPerSecond { rate, within_secs } => {
+                    self.time_played_seconds() <= within_secs
+                        && self.total_energy_per_second() >= rate
+                }
+                AchievementRequirement::SoleProducer { producer_id, count } => {
+                    self.producer_count(producer_id) >= count
+                        && self.total_producers_owned() == self.producer_count(producer_id)
+                }
+                AchievementRequirement::ChipsInOneAscension(chips) => {
+                    self.best_ascension_chips >= chips
+                }
+                AchievementRequirement::AllPrestigeUpgrades => PrestigeUpgrade::all()
+                    .iter()
+                    .all(|u| self.prestige_upgrades.contains(&u.id)),
+                AchievementRequirement::ClickStreak(clicks) => self.best_click_streak >= clicks,
+                AchievementRequirement::EveryProducer(count) => Producer::all()
+                    .iter()
+                    .all(|p| self.producer_count(p.id) >= count),
+                AchievementRequirement::AllCountBonuses => Producer::all()
+                    .iter()
+                    .all(|p| self.next_count_bonus(p.id).is_none()),
             };
 
             if earned {
                 self.achievements_unlocked.push(achievement.id);
                 self.new_achievements.push(achievement.id);
+                self.invalidate_multipliers();
+                self.event_log.push(
+                    EventKind::Achievement,
+                    format!("{}: {}", achievement.name, achievement.description),
+                );
+            }
+        }
+    }
+
+    /// Progress towards an achievement requirement, with an ETA for the ones that
+    /// advance on their own (lifetime energy and time played)
+    pub fn achievement_progress(
+        &self,
+        requirement: &AchievementRequirement,
+    ) -> AchievementProgress {
+        match *requirement {
+            AchievementRequirement::ProducerCount {
+                producer_id,
+                count: target,
+            } => AchievementProgress::new(self.producer_count(producer_id) as f64, target as f64),
+            AchievementRequirement::TotalEnergyPerSecond(rate) => {
+                AchievementProgress::new(self.total_energy_per_second(), rate)
+            }
+            AchievementRequirement::TotalEnergyEarned(amount) => {
+                let eps = self.total_energy_per_second();
+                let remaining = amount - self.total_energy_earned;
+                AchievementProgress {
+                    eta_secs: (eps > 0.0 && remaining > 0.0)
+                        .then(|| (remaining / eps).ceil() as u64),
+                    ..AchievementProgress::new(self.total_energy_earned, amount)
+                }
+            }
+            AchievementRequirement::TotalClicks(clicks) => {
+                AchievementProgress::new(self.total_manual_clicks as f64, clicks as f64)
+            }
+            AchievementRequirement::UpgradesPurchased(target) => {
+                AchievementProgress::new(self.upgrades_purchased.len() as f64, target as f64)
+            }
+            AchievementRequirement::TimePlayed(secs) => {
+                let played = self.time_played_seconds();
+                AchievementProgress {
+                    eta_secs: Some(secs.saturating_sub(played)),
+                    ..AchievementProgress::new(played as f64, secs as f64)
+                }
+            }
+            AchievementRequirement::TotalProducers(target) => {
+                AchievementProgress::new(self.total_producers_owned() as f64, target as f64)
+            }
+            AchievementRequirement::Ascensions(target) => {
+                AchievementProgress::new(self.total_ascensions as f64, target as f64)
+            }
+            AchievementRequirement::SpeedEnergyPerSecond { rate, within_secs } => {
+                // Once the run is older than the window only a new run can earn it
+                let current = if self.time_played_seconds() <= within_secs {
+                    self.total_energy_per_second()
+                } else {
+                    0.0
+                };
+                AchievementProgress::new(current, rate)
+            }
+            AchievementRequirement::SoleProducer {
+                producer_id,
+                count: target,
+            } => {
+                let owned = self.producer_count(producer_id);
+                let current = if self.total_producers_owned() == owned {
+                    owned
+                } else {
+                    0
+                };
+                AchievementProgress::new(current as f64, target as f64)
+            }
+            AchievementRequirement::ChipsInOneAscension(chips) => {
+                let best = self
+                    .best_ascension_chips
+                    .max(self.calculate_potential_stellar_chips());
+                AchievementProgress::new(best as f64, chips as f64)
+            }
+            AchievementRequirement::AllPrestigeUpgrades => AchievementProgress::new(
+                self.prestige_upgrades.len() as f64,
+                PrestigeUpgrade::all().len() as f64,
+            ),
+            AchievementRequirement::ClickStreak(clicks) => {
+                AchievementProgress::new(self.best_click_streak as f64, clicks as f64)
+            }
+            AchievementRequirement::EveryProducer(target) => {
+                let owned: u64 = Producer::all()
+                    .iter()
+                    .map(|p| self.producer_count(p.id).min(target))
+                    .sum();
+                AchievementProgress::new(
+                    owned as f64,
+                    (target * Producer::all().len() as u64) as f64,
+                )
+            }
+            AchievementRequirement::AllCountBonuses => {
+                let reached: usize = Producer::all()
+                    .iter()
+                    .map(|p| self.reached_count_bonuses(p.id).count())
+                    .sum();
+                let total: usize = Producer::all().iter().map(|p| p.milestones.len()).sum();
+                AchievementProgress::new(reached as f64, total as f64)
             }
         }
     }
@@ -720,7 +918,7 @@ impl GameState {
     /// Pop a new achievement for notification display
     pub fn pop_new_achievement(&mut self) -> Option<&'static Achievement> {
         let id = self.new_achievements.pop()?;
-        Achievement::all().iter().find(|a| a.id == id)
+        Achievement::by_id(id)
     }
 
     // ============ Prestige System ============
@@ -775,17 +973,31 @@ impl GameState {
         self.stellar_chips += chips_earned;
         self.total_stellar_chips_earned += chips_earned;
         self.total_ascensions += 1;
+        self.best_ascension_chips = self.best_ascension_chips.max(chips_earned);
+        self.event_log.push(
+            EventKind::Prestige,
+            format!(
+                "Ascension #{} for {} Stellar Chips",
+                self.total_ascensions, chips_earned
+            ),
+        );
 
         // Reset game state
         self.energy = starting_energy + kept_energy;
         self.total_energy_earned = 0.0;
         self.producers_owned.clear();
         self.upgrades_purchased.clear();
+        self.invalidate_multipliers();
         self.ticks_played = 0;
         self.total_manual_clicks = 0;
+        self.click_streak = 0;
+        self.last_click_tick = 0;
         self.energy_produced_history.clear();
         self.producer_lifetime_energy.clear();
-        // Keep: achievements_unlocked, stellar_chips, prestige_upgrades, total_ascensions
+        self.fusion_uptime_ticks = 0;
+        self.forge_overdrive_ticks = 0;
+        // Keep: achievements_unlocked, stellar_chips, prestige_upgrades, total_ascensions,
+        // purchase_queue, event_log
 
         // Note: Achievements are kept across ascensions!
     }
@@ -870,6 +1082,207 @@ impl GameState {
 
         self.stellar_chips -= upgrade.cost;
         self.prestige_upgrades.push(id);
+        self.invalidate_multipliers();
+        self.event_log.push(
+            EventKind::Prestige,
+            format!("Prestige upgrade: {}", upgrade.name),
+        );
         true
     }
 }
+
+impl Default for GameState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sell_producer_refund() {
+        let mut game = GameState::new();
+        game.energy = 15.0;
+        assert!(game.buy_producer(1, 1));
+        let earned = game.total_energy_earned;
+
+        assert!(game.sell_producer(1, 1));
+        assert!((game.energy - 7.5).abs() < 0.001);
+        assert_eq!(game.producer_count(1), 0);
+        // Refunds are not earnings
+        assert_eq!(game.total_energy_earned, earned);
+
+        assert!(!game.sell_producer(1, 1));
+    }
+
+    #[test]
+    fn test_selling_lower_tier_keeps_higher_tiers_visible() {
+        let mut game = GameState::new();
+        game.producers_owned.insert(1, 1);
+        game.producers_owned.insert(2, 1);
+        assert_eq!(game.visible_producers().len(), 3);
+
+        assert!(game.sell_producer(1, 1));
+        assert!(game.is_producer_unlocked(2));
+        assert_eq!(game.visible_producers().len(), 3);
+
+        // Selling the top tier re-locks the tier above it
+        assert!(game.sell_producer(2, 1));
+        assert_eq!(game.visible_producers().len(), 1);
+    }
+
+    #[test]
+    fn test_next_producer_milestone() {
+        let mut game = GameState::new();
+        assert_eq!(game.next_producer_milestone(1), Some(1));
+
+        game.producers_owned.insert(1, 1);
+        // 10 Solar Panels unlocks the next panel upgrade
+        assert_eq!(game.next_producer_milestone(1), Some(10));
+
+        game.producers_owned.insert(1, 10);
+        assert_eq!(game.next_producer_milestone(1), Some(25));
+
+        game.producers_owned.insert(1, 100_000);
+        assert_eq!(game.next_producer_milestone(1), None);
+    }
+
+    #[test]
+    fn test_count_bonuses() {
+        let mut game = GameState::new();
+        assert_eq!(game.get_count_bonus_multiplier(3), 1.0);
+        assert_eq!(game.next_count_bonus(3).map(|m| m.count), Some(25));
+
+        game.producers_owned.insert(3, 50);
+        assert!((game.get_count_bonus_multiplier(3) - 2.25).abs() < 1e-9);
+        assert_eq!(game.next_count_bonus(3).map(|m| m.count), Some(100));
+        assert_eq!(game.get_count_bonus_global_multiplier(), 1.0);
+
+        // 200 of a producer also boosts every producer
+        game.producers_owned.insert(3, 200);
+        assert!((game.get_count_bonus_global_multiplier() - 1.02).abs() < 1e-9);
+        assert!((game.get_count_bonus_multiplier(3) - 9.0).abs() < 1e-9);
+    }
+
+    fn achievement_id(name: &str) -> u32 {
+        Achievement::all()
+            .iter()
+            .find(|a| a.name == name)
+            .map(|a| a.id)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_click_streak() {
+        let mut game = GameState::new();
+        for _ in 0..3 {
+            game.manual_mine();
+            game.ticks_played += CLICK_STREAK_WINDOW_TICKS;
+        }
+        assert_eq!(game.click_streak, 3);
+
+        // A pause longer than the window starts a new streak
+        game.ticks_played += 1;
+        game.manual_mine();
+        assert_eq!(game.click_streak, 1);
+        assert_eq!(game.best_click_streak, 3);
+    }
+
+    #[test]
+    fn test_sole_producer_achievement() {
+        let purist = achievement_id("Purist");
+        let mut game = GameState::new();
+        game.producers_owned.insert(1, 100);
+        game.producers_owned.insert(2, 1);
+        game.check_achievements();
+        assert!(!game.achievements_unlocked.contains(&purist));
+
+        game.producers_owned.remove(&2);
+        game.check_achievements();
+        assert!(game.achievements_unlocked.contains(&purist));
+    }
+
+    #[test]
+    fn test_speed_achievement_window() {
+        let quick_start = achievement_id("Quick Start");
+        let mut game = GameState::new();
+        game.producers_owned.insert(5, 100);
+        game.ticks_played = 301 * 10;
+        game.check_achievements();
+        assert!(!game.achievements_unlocked.contains(&quick_start));
+
+        game.ticks_played = 300 * 10;
+        game.check_achievements();
+        assert!(game.achievements_unlocked.contains(&quick_start));
+    }
+
+    #[test]
+    fn test_secret_achievements_exist() {
+        assert!(Achievement::all().iter().any(|a| a.hidden));
+        assert!(Achievement::all()
+            .iter()
+            .filter(|a| a.hidden)
+            .all(|a| !matches!(a.requirement, AchievementRequirement::ProducerCount { .. })));
+    }
+
+    #[test]
+    fn test_achievement_categories() {
+        let total: usize = AchievementCategory::ALL
+            .iter()
+            .map(|&c| GameState::new().achievement_category_progress(c).1)
+            .sum();
+        assert_eq!(total, Achievement::all().len());
+        assert!(AchievementCategory::ALL
+            .iter()
+            .all(|&c| GameState::new().achievement_category_progress(c).1 > 0));
+
+        let mut game = GameState::new();
+        game.producers_owned.insert(1, 10);
+        game.total_manual_clicks = 100;
+        game.check_achievements();
+        assert_eq!(
+            game.achievement_category_progress(AchievementCategory::Producers),
+            (2, 180)
+        );
+
+        // Category bonuses multiply back to the full achievement bonus
+        let (multiplier, essence) = AchievementCategory::ALL.iter().fold((1.0, 0.0), |acc, &c| {
+            let (m, e) = game.achievement_category_bonus(c);
+            (acc.0 * m, acc.1 + e)
+        });
+        assert!((multiplier - game.get_achievement_multiplier()).abs() < 1e-9);
+        assert!((essence - game.get_stellar_essence()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_achievement_progress() {
+        let mut game = GameState::new();
+        game.producers_owned.insert(1, 5);
+        let progress = game.achievement_progress(&AchievementRequirement::ProducerCount {
+            producer_id: 1,
+            count: 10,
+        });
+        assert_eq!(progress.fraction(), 0.5);
+        assert_eq!(progress.eta_secs, None);
+
+        game.ticks_played = 30 * 10;
+        let progress = game.achievement_progress(&AchievementRequirement::TimePlayed(60));
+        assert_eq!(progress.eta_secs, Some(30));
+
+        // Lifetime energy ETA follows the current E/s
+        let eps = game.total_energy_per_second();
+        let progress =
+            game.achievement_progress(&AchievementRequirement::TotalEnergyEarned(eps * 100.0));
+        assert_eq!(progress.eta_secs, Some(100));
+
+        // Owning a second producer resets sole-producer progress
+        game.producers_owned.insert(2, 1);
+        let progress = game.achievement_progress(&AchievementRequirement::SoleProducer {
+            producer_id: 1,
+            count: 10,
+        });
+        assert_eq!(progress.fraction(), 0.0);
+    }
+}
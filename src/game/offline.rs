@@ -0,0 +1,38 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::GameState;
+use crate::TICKS_PER_SECOND;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct OfflineReport {
+    pub duration_secs: u64,
+    pub energy_earned: f64,
+}
+
+/// Offline progress is capped at 8 hours
+pub const MAX_OFFLINE_SECS: u64 = 8 * 60 * 60;
+
+/// Energy a game earns between `last_save` and `now` while nobody is playing,
+/// or `None` if that was a minute or less
+pub fn offline_progress(
+    game: &GameState,
+    last_save: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> Option<OfflineReport> {
+    let elapsed_secs = now.signed_duration_since(last_save).num_seconds().max(0) as u64;
+    let capped_secs = elapsed_secs.min(MAX_OFFLINE_SECS);
+    if capped_secs <= 60 {
+        return None;
+    }
+
+    let energy_per_tick = game.total_energy_per_second() / TICKS_PER_SECOND;
+    let ticks = capped_secs * TICKS_PER_SECOND as u64;
+
+    // Apply offline bonus from prestige upgrades
+    let offline_bonus = game.get_offline_bonus_multiplier();
+    Some(OfflineReport {
+        duration_secs: capped_secs,
+        energy_earned: energy_per_tick * ticks as f64 * offline_bonus,
+    })
+}
@@ -3,10 +3,39 @@ pub struct Producer {
     pub id: u32,
     pub name: &'static str,
     pub icon: &'static str,
+    /// Single-character stand-in for `icon` in ASCII mode
+    pub ascii_icon: &'static str,
     pub base_energy_per_second: f64,
     pub base_cost: f64,
     #[allow(dead_code)]
     pub description: &'static str,
+    /// Built-in bonuses granted when the owned count reaches each threshold
+    pub milestones: &'static [ProducerMilestone],
+}
+
+/// A bonus that applies once a producer's owned count reaches `count`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProducerMilestone {
+    pub count: u64,
+    pub effect: MilestoneEffect,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MilestoneEffect {
+    /// Multiplies this producer's E/s (stacks with every other reached milestone)
+    ProducerMultiplier(f64),
+    /// Multiplies total E/s of all producers
+    GlobalMultiplier(f64),
+}
+
+impl MilestoneEffect {
+    /// Short label for the detail view, e.g. "x1.5" or "+2% all"
+    pub fn label(&self) -> String {
+        match self {
+            MilestoneEffect::ProducerMultiplier(m) => format!("x{}", m),
+            MilestoneEffect::GlobalMultiplier(m) => format!("+{:.0}% all", (m - 1.0) * 100.0),
+        }
+    }
 }
 
 impl Producer {
@@ -15,6 +44,38 @@ impl Producer {
     }
 }
 
+const fn milestone(count: u64, effect: MilestoneEffect) -> ProducerMilestone {
+    ProducerMilestone { count, effect }
+}
+
+// Default thresholds for most producers
+// Every 200 owned also gives a small global boost, rewarding wide investment
+static STANDARD_MILESTONES: [ProducerMilestone; 9] = [
+    milestone(25, MilestoneEffect::ProducerMultiplier(1.5)),
+    milestone(50, MilestoneEffect::ProducerMultiplier(1.5)),
+    milestone(100, MilestoneEffect::ProducerMultiplier(2.0)),
+    milestone(150, MilestoneEffect::ProducerMultiplier(2.0)),
+    milestone(200, MilestoneEffect::GlobalMultiplier(1.02)),
+    milestone(250, MilestoneEffect::ProducerMultiplier(2.0)),
+    milestone(300, MilestoneEffect::ProducerMultiplier(2.0)),
+    milestone(400, MilestoneEffect::GlobalMultiplier(1.02)),
+    milestone(500, MilestoneEffect::ProducerMultiplier(3.0)),
+];
+
+// Solar Panels and Mining Drones are bought in far larger numbers,
+// so their thresholds are spaced wider and pay out global boosts more often
+static EARLY_MILESTONES: [ProducerMilestone; 9] = [
+    milestone(50, MilestoneEffect::ProducerMultiplier(1.5)),
+    milestone(100, MilestoneEffect::ProducerMultiplier(2.0)),
+    milestone(200, MilestoneEffect::GlobalMultiplier(1.02)),
+    milestone(300, MilestoneEffect::ProducerMultiplier(2.0)),
+    milestone(400, MilestoneEffect::GlobalMultiplier(1.02)),
+    milestone(500, MilestoneEffect::ProducerMultiplier(2.0)),
+    milestone(600, MilestoneEffect::GlobalMultiplier(1.02)),
+    milestone(800, MilestoneEffect::ProducerMultiplier(3.0)),
+    milestone(1000, MilestoneEffect::GlobalMultiplier(1.05)),
+];
+
 // 20 Producers with incremental cost/E/s ratios
 // Each producer has progressively longer payback times (cost/E/s)
 // This creates the characteristic slow-down as you progress
@@ -25,9 +86,11 @@ static PRODUCERS: [Producer; 20] = [
         id: 1,
         name: "Solar Panel",
         icon: "▤",
+        ascii_icon: "#",
         base_energy_per_second: 0.1,
         base_cost: 15.0,
         description: "A simple photovoltaic cell",
+        milestones: &EARLY_MILESTONES,
     },
     // Producer 2: Mining Drone
     // Special: Boosted by Drone Network from other buildings
@@ -35,170 +98,208 @@ static PRODUCERS: [Producer; 20] = [
         id: 2,
         name: "Mining Drone",
         icon: "⛏",
+        ascii_icon: "d",
         base_energy_per_second: 1.0,
         base_cost: 100.0,
         description: "Autonomous resource collector",
+        milestones: &EARLY_MILESTONES,
     },
     // Producer 3: Asteroid Mine
     Producer {
         id: 3,
         name: "Asteroid Mine",
         icon: "☄",
+        ascii_icon: "a",
         base_energy_per_second: 8.0,
         base_cost: 1_100.0,
         description: "Harvests energy-rich minerals",
+        milestones: &STANDARD_MILESTONES,
     },
     // Producer 4: Orbital Station
     Producer {
         id: 4,
         name: "Orbital Station",
         icon: "◎",
+        ascii_icon: "o",
         base_energy_per_second: 47.0,
         base_cost: 12_000.0,
         description: "Space-based power relay",
+        milestones: &STANDARD_MILESTONES,
     },
     // Producer 5: Lunar Colony
     Producer {
         id: 5,
         name: "Lunar Colony",
         icon: "☽",
+        ascii_icon: "c",
         base_energy_per_second: 260.0,
         base_cost: 130_000.0,
         description: "Moon-based operations hub",
+        milestones: &STANDARD_MILESTONES,
     },
     // Producer 6: Planetary Harvester
     Producer {
         id: 6,
         name: "Planetary Harvester",
         icon: "⊕",
+        ascii_icon: "p",
         base_energy_per_second: 1_400.0,
         base_cost: 1_400_000.0,
         description: "Extracts planetary core energy",
+        milestones: &STANDARD_MILESTONES,
     },
     // Producer 7: Fusion Reactor
     Producer {
         id: 7,
         name: "Fusion Reactor",
         icon: "✴",
+        ascii_icon: "f",
         base_energy_per_second: 7_800.0,
         base_cost: 20_000_000.0,
         description: "Harnesses stellar fusion",
+        milestones: &STANDARD_MILESTONES,
     },
     // Producer 8: Dyson Swarm
     Producer {
         id: 8,
         name: "Dyson Swarm",
         icon: "✧",
+        ascii_icon: "s",
         base_energy_per_second: 44_000.0,
         base_cost: 330_000_000.0,
         description: "Orbiting solar collectors",
+        milestones: &STANDARD_MILESTONES,
     },
     // Producer 9: Dyson Sphere
     Producer {
         id: 9,
         name: "Dyson Sphere",
         icon: "⊙",
+        ascii_icon: "O",
         base_energy_per_second: 260_000.0,
         base_cost: 5_100_000_000.0,
         description: "Encapsulates an entire star",
+        milestones: &STANDARD_MILESTONES,
     },
     // Producer 10: Star Forge
     Producer {
         id: 10,
         name: "Star Forge",
         icon: "★",
+        ascii_icon: "*",
         base_energy_per_second: 1_600_000.0,
         base_cost: 75_000_000_000.0,
         description: "Creates and harvests stars",
+        milestones: &STANDARD_MILESTONES,
     },
     // Producer 11: Neutron Harvester
     Producer {
         id: 11,
         name: "Neutron Harvester",
         icon: "◉",
+        ascii_icon: "n",
         base_energy_per_second: 10_000_000.0,
         base_cost: 1_000_000_000_000.0,
         description: "Harvests neutron star energy",
+        milestones: &STANDARD_MILESTONES,
     },
     // Producer 12: Black Hole Tap
     Producer {
         id: 12,
         name: "Black Hole Tap",
         icon: "●",
+        ascii_icon: "@",
         base_energy_per_second: 65_000_000.0,
         base_cost: 14_000_000_000_000.0,
         description: "Extracts energy from event horizons",
+        milestones: &STANDARD_MILESTONES,
     },
     // Producer 13: Quantum Vacuum
     Producer {
         id: 13,
         name: "Quantum Vacuum",
         icon: "◌",
+        ascii_icon: "q",
         base_energy_per_second: 430_000_000.0,
         base_cost: 170_000_000_000_000.0,
         description: "Zero-point energy extraction",
+        milestones: &STANDARD_MILESTONES,
     },
     // Producer 14: Galactic Core
     Producer {
         id: 14,
         name: "Galactic Core",
         icon: "⊛",
+        ascii_icon: "G",
         base_energy_per_second: 2_900_000_000.0,
         base_cost: 2_100_000_000_000_000.0,
         description: "Taps supermassive black holes",
+        milestones: &STANDARD_MILESTONES,
     },
     // Producer 15: Dark Matter Engine
     Producer {
         id: 15,
         name: "Dark Matter Engine",
         icon: "◐",
+        ascii_icon: "m",
         base_energy_per_second: 21_000_000_000.0,
         base_cost: 26_000_000_000_000_000.0,
         description: "Converts dark matter to energy",
+        milestones: &STANDARD_MILESTONES,
     },
     // Producer 16: Cosmic String
     Producer {
         id: 16,
         name: "Cosmic String",
         icon: "≋",
+        ascii_icon: "~",
         base_energy_per_second: 150_000_000_000.0,
         base_cost: 310_000_000_000_000_000.0,
         description: "Vibrating cosmic strings",
+        milestones: &STANDARD_MILESTONES,
     },
     // Producer 17: Multiverse Gate
     Producer {
         id: 17,
         name: "Multiverse Gate",
         icon: "⧫",
+        ascii_icon: "%",
         base_energy_per_second: 1_100_000_000_000.0,
         base_cost: 71_000_000_000_000_000_000.0,
         description: "Energy from parallel universes",
+        milestones: &STANDARD_MILESTONES,
     },
     // Producer 18: Reality Compiler
     Producer {
         id: 18,
         name: "Reality Compiler",
         icon: "⌘",
+        ascii_icon: "&",
         base_energy_per_second: 8_300_000_000_000.0,
         base_cost: 12_000_000_000_000_000_000_000.0,
         description: "Rewrites physics for energy",
+        milestones: &STANDARD_MILESTONES,
     },
     // Producer 19: Entropy Reverser
     Producer {
         id: 19,
         name: "Entropy Reverser",
         icon: "∞",
+        ascii_icon: "8",
         base_energy_per_second: 64_000_000_000_000.0,
         base_cost: 1_900_000_000_000_000_000_000_000.0,
         description: "Reverses thermodynamic entropy",
+        milestones: &STANDARD_MILESTONES,
     },
     // Producer 20: Big Bang Catalyst
     Producer {
         id: 20,
         name: "Big Bang Catalyst",
         icon: "✦",
+        ascii_icon: "!",
         base_energy_per_second: 510_000_000_000_000.0,
         base_cost: 540_000_000_000_000_000_000_000_000.0,
         description: "Creates pocket universes",
+        milestones: &STANDARD_MILESTONES,
     },
 ];
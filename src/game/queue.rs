@@ -0,0 +1,194 @@
+use serde::{Deserialize, Serialize};
+
+use super::{calculate_bulk_cost, GameState, Producer, Upgrade};
+
+/// Maximum number of entries the purchase queue can hold
+pub const MAX_QUEUE_LEN: usize = 50;
+
+/// A single entry in the player-authored purchase queue
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum QueuedPurchase {
+    Producer { id: u32, quantity: u64 },
+    Upgrade { id: u32 },
+}
+
+impl QueuedPurchase {
+    /// Display name of the queued item
+    pub fn name(&self) -> &'static str {
+        match self {
+            QueuedPurchase::Producer { id, .. } => Producer::all()
+                .iter()
+                .find(|p| p.id == *id)
+                .map(|p| p.name)
+                .unwrap_or("Unknown producer"),
+            QueuedPurchase::Upgrade { id } => Upgrade::by_id(*id)
+                .map(|u| u.name)
+                .unwrap_or("Unknown upgrade"),
+        }
+    }
+}
+
+impl GameState {
+    /// Append a purchase to the end of the queue.
+    /// Returns false if the queue is full or the upgrade is already owned/queued.
+    pub fn enqueue_purchase(&mut self, item: QueuedPurchase) -> bool {
+        if self.purchase_queue.len() >= MAX_QUEUE_LEN {
+            return false;
+        }
+
+        if let QueuedPurchase::Upgrade { id } = item {
+            // Upgrades are one-time purchases, so queueing twice makes no sense
+            if self.upgrades_purchased.contains(&id) || self.purchase_queue.contains(&item) {
+                return false;
+            }
+        }
+
+        self.purchase_queue.push(item);
+        true
+    }
+
+    /// Remove the queue entry at `index`
+    pub fn remove_queued_purchase(&mut self, index: usize) -> Option<QueuedPurchase> {
+        if index < self.purchase_queue.len() {
+            Some(self.purchase_queue.remove(index))
+        } else {
+            None
+        }
+    }
+
+    /// Move the queue entry at `index` one slot towards the head.
+    /// Returns the entry's new index.
+    pub fn move_queued_purchase_up(&mut self, index: usize) -> usize {
+        if index > 0 && index < self.purchase_queue.len() {
+            self.purchase_queue.swap(index, index - 1);
+            index - 1
+        } else {
+            index
+        }
+    }
+
+    /// Move the queue entry at `index` one slot towards the tail.
+    /// Returns the entry's new index.
+    pub fn move_queued_purchase_down(&mut self, index: usize) -> usize {
+        if index + 1 < self.purchase_queue.len() {
+            self.purchase_queue.swap(index, index + 1);
+            index + 1
+        } else {
+            index
+        }
+    }
+
+    /// Current cost of a queued item (producer bulk cost or discounted upgrade cost)
+    pub fn queued_purchase_cost(&self, item: &QueuedPurchase) -> f64 {
+        match item {
+            QueuedPurchase::Producer { id, quantity } => {
+                match Producer::all().iter().find(|p| p.id == *id) {
+                    Some(producer) => calculate_bulk_cost(
+                        producer.base_cost,
+                        self.producer_count(*id),
+                        *quantity,
+                        *id,
+                    ),
+                    None => 0.0,
+                }
+            }
+            QueuedPurchase::Upgrade { id } => match Upgrade::by_id(*id) {
+                Some(upgrade) => self.get_upgrade_cost(upgrade),
+                None => 0.0,
+            },
+        }
+    }
+
+    /// Execute queued purchases from the head for as long as they succeed.
+    /// Goes through `buy_producer`/`buy_upgrade` so every unlock and cost rule applies.
+    /// Entries that can never succeed (unknown ids, already-owned upgrades) are dropped.
+    pub fn process_purchase_queue(&mut self) -> usize {
+        let mut executed = 0;
+
+        while let Some(&head) = self.purchase_queue.first() {
+            let bought = match head {
+                QueuedPurchase::Producer { id, quantity } => {
+                    if quantity == 0 || !Producer::all().iter().any(|p| p.id == id) {
+                        self.purchase_queue.remove(0);
+                        continue;
+                    }
+                    self.buy_producer(id, quantity)
+                }
+                QueuedPurchase::Upgrade { id } => {
+                    if self.upgrades_purchased.contains(&id) || Upgrade::by_id(id).is_none() {
+                        self.purchase_queue.remove(0);
+                        continue;
+                    }
+                    self.buy_upgrade(id)
+                }
+            };
+
+            if !bought {
+                break;
+            }
+
+            self.purchase_queue.remove(0);
+            executed += 1;
+        }
+
+        executed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_head_executes_when_affordable() {
+        let mut game = GameState::new();
+        game.enqueue_purchase(QueuedPurchase::Producer { id: 1, quantity: 1 });
+
+        game.energy = 10.0;
+        assert_eq!(game.process_purchase_queue(), 0);
+        assert_eq!(game.purchase_queue.len(), 1);
+
+        game.energy = 15.0;
+        assert_eq!(game.process_purchase_queue(), 1);
+        assert_eq!(game.producer_count(1), 1);
+        assert!(game.purchase_queue.is_empty());
+    }
+
+    #[test]
+    fn test_head_blocks_later_entries() {
+        let mut game = GameState::new();
+        game.energy = 50.0;
+        // Mining Drone is locked until a Solar Panel is owned, so it blocks the queue
+        game.enqueue_purchase(QueuedPurchase::Producer { id: 2, quantity: 1 });
+        game.enqueue_purchase(QueuedPurchase::Producer { id: 1, quantity: 1 });
+
+        assert_eq!(game.process_purchase_queue(), 0);
+        assert_eq!(game.producer_count(1), 0);
+    }
+
+    #[test]
+    fn test_owned_upgrades_are_dropped() {
+        let mut game = GameState::new();
+        game.purchase_queue
+            .push(QueuedPurchase::Upgrade { id: 101 });
+        game.upgrades_purchased.push(101);
+        game.enqueue_purchase(QueuedPurchase::Producer { id: 1, quantity: 1 });
+        game.energy = 15.0;
+
+        assert_eq!(game.process_purchase_queue(), 1);
+        assert!(game.purchase_queue.is_empty());
+    }
+
+    #[test]
+    fn test_reorder() {
+        let mut game = GameState::new();
+        game.enqueue_purchase(QueuedPurchase::Producer { id: 1, quantity: 1 });
+        game.enqueue_purchase(QueuedPurchase::Upgrade { id: 101 });
+        assert!(!game.enqueue_purchase(QueuedPurchase::Upgrade { id: 101 }));
+
+        assert_eq!(game.move_queued_purchase_up(1), 0);
+        assert_eq!(game.purchase_queue[0], QueuedPurchase::Upgrade { id: 101 });
+        assert_eq!(game.move_queued_purchase_down(0), 1);
+        assert_eq!(game.move_queued_purchase_down(1), 1);
+    }
+}
@@ -0,0 +1,397 @@
+use super::{GameState, Producer, UpgradeEffect};
+use crate::TICKS_PER_SECOND;
+
+/// Star Forges only burn energy above this many seconds of current production
+const FORGE_RESERVE_SECS: f64 = 3600.0;
+/// Fraction of the excess energy Star Forges burn per second
+const FORGE_BURN_PER_SECOND: f64 = 0.01;
+/// How long the overdrive boost lingers after burning stops
+const FORGE_LINGER_TICKS: u64 = 30 * TICKS_PER_SECOND as u64;
+/// Quantum Vacuums are boosted while banked energy is below this many seconds of production
+const ZERO_POINT_SECS: f64 = 60.0;
+
+impl GameState {
+    /// Current bonus from a producer's signature mechanic as a fraction (0.5 = +50%),
+    /// including amplifier upgrades. Zero if the mechanic is not unlocked.
+    pub fn signature_bonus(&self, producer_id: u32) -> f64 {
+        let cache = self.multipliers();
+
+        let bonus: f64 = cache
+            .signature_effects
+            .iter()
+            .filter(|(pid, _)| *pid == producer_id)
+            .map(|(_, effect)| self.signature_base_bonus(*effect))
+            .sum();
+        let amplifier = cache
+            .signature_amplifiers
+            .get(producer_id as usize - 1)
+            .copied()
+            .unwrap_or(1.0);
+
+        bonus * amplifier
+    }
+
+    /// Production multiplier a producer gets from its own signature mechanic
+    pub fn get_signature_multiplier(&self, producer_id: u32) -> f64 {
+        // Galactic Core's mechanic boosts everything instead, see get_signature_global_multiplier
+        if producer_id == 14 {
+            return 1.0;
+        }
+        1.0 + self.signature_bonus(producer_id)
+    }
+
+    /// Global multiplier from signature mechanics (Galactic Gravity)
+    pub fn get_signature_global_multiplier(&self) -> f64 {
+        1.0 + self.signature_bonus(14)
+    }
+
+    /// Whether a producer's signature mechanic has been unlocked
+    pub fn has_signature(&self, producer_id: u32) -> bool {
+        self.multipliers()
+            .signature_effects
+            .iter()
+            .any(|(pid, _)| *pid == producer_id)
+    }
+
+    /// Bonus granted by a single mechanic effect, before amplifiers
+    fn signature_base_bonus(&self, effect: UpgradeEffect) -> f64 {
+        match effect {
+            UpgradeEffect::AsteroidProspecting {
+                bonus_per_hundred_clicks,
+            } => (self.total_manual_clicks / 100) as f64 * bonus_per_hundred_clicks,
+            UpgradeEffect::OrbitalRelay { bonus_per_type } => {
+                let types = Producer::all()
+                    .iter()
+                    .filter(|p| self.producer_count(p.id) > 0)
+                    .count();
+                types as f64 * bonus_per_type
+            }
+            UpgradeEffect::LunarCycle {
+                amplitude,
+                period_secs,
+            } => {
+                let period_ticks = (period_secs * TICKS_PER_SECOND as u64).max(1);
+                let phase = (self.ticks_played % period_ticks) as f64 / period_ticks as f64;
+                // Starts at half strength, peaks a quarter of the way through the cycle
+                amplitude * (0.5 + 0.5 * (phase * std::f64::consts::TAU).sin())
+            }
+            UpgradeEffect::HarvestIndex { bonus_per_upgrade } => {
+                self.upgrades_purchased.len() as f64 * bonus_per_upgrade
+            }
+            UpgradeEffect::FusionRampUp {
+                bonus_per_minute,
+                max_bonus,
+            } => {
+                let minutes = self.fusion_uptime_ticks as f64 / (60.0 * TICKS_PER_SECOND);
+                (minutes * bonus_per_minute).min(max_bonus)
+            }
+            UpgradeEffect::SwarmCollection { bonus_per_panel } => {
+                self.producer_count(1) as f64 * bonus_per_panel
+            }
+            UpgradeEffect::SphereShells { bonus_per_shell } => {
+                let shells = Producer::all()
+                    .iter()
+                    .filter(|p| self.producer_count(p.id) >= 100)
+                    .count();
+                shells as f64 * bonus_per_shell
+            }
+            // Only while Star Forges are burning excess energy (see tick_signature_mechanics)
+            UpgradeEffect::ForgeOverdrive { bonus } if self.forge_overdrive_ticks > 0 => bonus,
+            UpgradeEffect::NeutronDensity {
+                bonus_per_magnitude,
+            } => self.all_time_energy_earned.max(1.0).log10().floor() * bonus_per_magnitude,
+            UpgradeEffect::BlackHoleAccretion { bonus_per_hundred } => {
+                let lower: u64 = (1..12).map(|id| self.producer_count(id)).sum();
+                (lower / 100) as f64 * bonus_per_hundred
+            }
+            UpgradeEffect::VacuumZeroPoint { bonus } => {
+                // Uses the measured rate so this never feeds back into itself
+                let eps = self.actual_energy_per_second();
+                if eps > 0.0 && self.energy < eps * ZERO_POINT_SECS {
+                    bonus
+                } else {
+                    0.0
+                }
+            }
+            UpgradeEffect::GalacticGravity { bonus_per_core } => {
+                self.producer_count(14) as f64 * bonus_per_core
+            }
+            UpgradeEffect::DarkMatterMass { bonus_per_chip } => {
+                self.stellar_chips as f64 * bonus_per_chip
+            }
+            UpgradeEffect::CosmicStringWeave { bonus_per_link } => {
+                let links = (1..20)
+                    .filter(|&id| {
+                        self.producer_count(id) >= 50 && self.producer_count(id + 1) >= 50
+                    })
+                    .count();
+                links as f64 * bonus_per_link
+            }
+            UpgradeEffect::MultiverseEchoes {
+                bonus_per_ascension,
+            } => self.total_ascensions as f64 * bonus_per_ascension,
+            UpgradeEffect::RealityCompilation {
+                bonus_per_achievement,
+            } => self.achievements_unlocked.len() as f64 * bonus_per_achievement,
+            UpgradeEffect::EntropyReversal {
+                bonus_per_hour,
+                max_bonus,
+            } => {
+                let hours = self.ticks_played as f64 / (3600.0 * TICKS_PER_SECOND);
+                (hours * bonus_per_hour).min(max_bonus)
+            }
+            UpgradeEffect::PrimordialSpark {
+                bonus_per_signature,
+            } => self.multipliers().signature_upgrades as f64 * bonus_per_signature,
+            _ => 0.0,
+        }
+    }
+
+    /// Advance stateful signature mechanics by one tick (Fusion uptime, Star Forge overdrive)
+    pub(super) fn tick_signature_mechanics(&mut self) {
+        if self.producer_count(7) > 0 {
+            self.fusion_uptime_ticks += 1;
+        } else {
+            self.fusion_uptime_ticks = 0;
+        }
+
+        let eps = self.actual_energy_per_second();
+        let reserve = eps * FORGE_RESERVE_SECS;
+        let burning = self.has_signature(10)
+            && self.producer_count(10) > 0
+            && eps > 0.0
+            && self.energy > reserve;
+        if burning {
+            let burn = (self.energy - reserve) * FORGE_BURN_PER_SECOND / TICKS_PER_SECOND;
+            self.energy -= burn;
+            self.forge_overdrive_ticks = FORGE_LINGER_TICKS;
+        } else {
+            self.forge_overdrive_ticks = self.forge_overdrive_ticks.saturating_sub(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{signature_upgrade_id, Upgrade};
+    use super::*;
+
+    fn with_signature(producer_id: u32, tiers: u32) -> GameState {
+        let mut game = GameState::new();
+        for tier in 1..=tiers {
+            game.upgrades_purchased
+                .push(signature_upgrade_id(producer_id, tier));
+        }
+        game
+    }
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "expected {}, got {}",
+            expected,
+            actual
+        );
+    }
+
+    #[test]
+    fn test_every_tier_has_a_chain() {
+        for producer_id in 3..=20 {
+            for tier in 1..=3 {
+                let id = signature_upgrade_id(producer_id, tier);
+                let upgrade = Upgrade::by_id(id);
+                assert!(upgrade.is_some(), "missing signature upgrade {}", id);
+                assert_eq!(
+                    upgrade.unwrap().effect.signature_producer(),
+                    Some(producer_id)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_locked_signature_has_no_effect() {
+        let mut game = GameState::new();
+        game.producers_owned.insert(1, 200);
+        assert_eq!(game.signature_bonus(8), 0.0);
+        assert_eq!(game.get_signature_multiplier(8), 1.0);
+    }
+
+    #[test]
+    fn test_amplifiers_double_bonus() {
+        let mut game = with_signature(8, 3);
+        game.producers_owned.insert(1, 100);
+        // 100 panels * 0.5% = 50%, doubled twice
+        assert_close(game.signature_bonus(8), 2.0);
+    }
+
+    #[test]
+    fn test_asteroid_prospecting() {
+        let mut game = with_signature(3, 1);
+        game.total_manual_clicks = 250;
+        assert_close(game.signature_bonus(3), 0.04);
+    }
+
+    #[test]
+    fn test_orbital_relay() {
+        let mut game = with_signature(4, 1);
+        game.producers_owned.insert(1, 1);
+        game.producers_owned.insert(4, 1);
+        game.producers_owned.insert(9, 1);
+        assert_close(game.signature_bonus(4), 0.15);
+    }
+
+    #[test]
+    fn test_lunar_cycle() {
+        let mut game = with_signature(5, 1);
+        assert_close(game.signature_bonus(5), 0.5);
+        // A quarter through the 2 minute cycle is the peak
+        game.ticks_played = 30 * 10;
+        assert_close(game.signature_bonus(5), 1.0);
+        game.ticks_played = 90 * 10;
+        assert_close(game.signature_bonus(5), 0.0);
+    }
+
+    #[test]
+    fn test_harvest_index() {
+        let game = with_signature(6, 2);
+        // 2 upgrades * 0.5%, doubled by the amplifier
+        assert_close(game.signature_bonus(6), 0.02);
+    }
+
+    #[test]
+    fn test_fusion_ramp_up_resets_on_sell() {
+        let mut game = with_signature(7, 1);
+        game.producers_owned.insert(7, 2);
+        for _ in 0..(10 * 60 * 10) {
+            game.tick_signature_mechanics();
+        }
+        assert_close(game.signature_bonus(7), 0.1);
+
+        game.fusion_uptime_ticks = 1_000_000;
+        assert_close(game.signature_bonus(7), 1.0);
+
+        assert!(game.sell_producer(7, 1));
+        assert_eq!(game.signature_bonus(7), 0.0);
+    }
+
+    #[test]
+    fn test_swarm_collection() {
+        let mut game = with_signature(8, 1);
+        game.producers_owned.insert(1, 40);
+        assert_close(game.signature_bonus(8), 0.2);
+    }
+
+    #[test]
+    fn test_sphere_shells() {
+        let mut game = with_signature(9, 1);
+        game.producers_owned.insert(1, 100);
+        game.producers_owned.insert(2, 150);
+        game.producers_owned.insert(3, 99);
+        assert_close(game.signature_bonus(9), 0.2);
+    }
+
+    #[test]
+    fn test_forge_overdrive_burns_excess() {
+        let mut game = with_signature(10, 1);
+        game.producers_owned.insert(10, 1);
+        game.energy_produced_history.push_back(1.0);
+        game.energy = FORGE_RESERVE_SECS + 1000.0;
+
+        game.tick_signature_mechanics();
+        // 1% per second of the 1000 excess, over one tick
+        assert_close(game.energy, FORGE_RESERVE_SECS + 999.0);
+        assert_close(game.signature_bonus(10), 1.0);
+
+        // Below the reserve the boost lingers, then fades
+        game.energy = 0.0;
+        for _ in 0..FORGE_LINGER_TICKS {
+            game.tick_signature_mechanics();
+        }
+        assert_eq!(game.signature_bonus(10), 0.0);
+    }
+
+    #[test]
+    fn test_neutron_density() {
+        let mut game = with_signature(11, 1);
+        game.all_time_energy_earned = 2.5e12;
+        assert_close(game.signature_bonus(11), 0.6);
+    }
+
+    #[test]
+    fn test_black_hole_accretion() {
+        let mut game = with_signature(12, 1);
+        game.producers_owned.insert(1, 150);
+        game.producers_owned.insert(5, 60);
+        // Black Hole Taps themselves don't count
+        game.producers_owned.insert(12, 500);
+        assert_close(game.signature_bonus(12), 0.1);
+    }
+
+    #[test]
+    fn test_vacuum_zero_point() {
+        let mut game = with_signature(13, 1);
+        game.energy_produced_history.push_back(10.0);
+        game.energy = 100.0;
+        assert_close(game.signature_bonus(13), 1.0);
+        game.energy = 10.0 * ZERO_POINT_SECS;
+        assert_eq!(game.signature_bonus(13), 0.0);
+    }
+
+    #[test]
+    fn test_galactic_gravity_is_global() {
+        let mut game = with_signature(14, 1);
+        game.producers_owned.insert(14, 50);
+        assert_close(game.get_signature_global_multiplier(), 1.05);
+        assert_eq!(game.get_signature_multiplier(14), 1.0);
+    }
+
+    #[test]
+    fn test_dark_matter_mass() {
+        let mut game = with_signature(15, 1);
+        game.stellar_chips = 30;
+        assert_close(game.signature_bonus(15), 0.3);
+    }
+
+    #[test]
+    fn test_cosmic_string_weave() {
+        let mut game = with_signature(16, 1);
+        for id in 1..=4 {
+            game.producers_owned.insert(id, 50);
+        }
+        // 1-2, 2-3 and 3-4 are linked
+        assert_close(game.signature_bonus(16), 0.3);
+    }
+
+    #[test]
+    fn test_multiverse_echoes() {
+        let mut game = with_signature(17, 1);
+        game.total_ascensions = 4;
+        assert_close(game.signature_bonus(17), 0.4);
+    }
+
+    #[test]
+    fn test_reality_compilation() {
+        let mut game = with_signature(18, 1);
+        game.achievements_unlocked = vec![1, 2, 3];
+        assert_close(game.signature_bonus(18), 0.03);
+    }
+
+    #[test]
+    fn test_entropy_reversal() {
+        let mut game = with_signature(19, 1);
+        game.ticks_played = 5 * 3600 * 10;
+        assert_close(game.signature_bonus(19), 0.5);
+        game.ticks_played = 100 * 3600 * 10;
+        assert_close(game.signature_bonus(19), 2.0);
+    }
+
+    #[test]
+    fn test_primordial_spark() {
+        let mut game = with_signature(20, 1);
+        game.upgrades_purchased.push(signature_upgrade_id(3, 1));
+        game.upgrades_purchased.push(signature_upgrade_id(3, 2));
+        game.upgrades_purchased.push(201);
+        assert_close(game.signature_bonus(20), 0.3);
+    }
+}
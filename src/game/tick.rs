@@ -6,6 +6,9 @@ impl GameState {
     pub fn tick(&mut self) {
         self.ticks_played += 1;
 
+        // Fusion uptime and Star Forge overdrive
+        self.tick_signature_mechanics();
+
         // Calculate and track per-producer energy production
         let global_mult = self.get_global_multiplier();
         let mut total_energy_per_tick = 0.0;
@@ -31,6 +34,9 @@ impl GameState {
 
         self.add_energy(total_energy_per_tick);
 
+        // Execute queued purchases that just became affordable
+        self.process_purchase_queue();
+
         // Keep this for backward compatibility with total_energy_per_second calculation
         let energy_per_tick = total_energy_per_tick;
 
@@ -41,7 +47,7 @@ impl GameState {
         }
 
         // Check for new achievements every second (every 10 ticks)
-        if self.ticks_played % 10 == 0 {
+        if self.ticks_played.is_multiple_of(10) {
             self.check_achievements();
         }
     }
@@ -1,4 +1,5 @@
 use once_cell::sync::Lazy;
+use std::collections::HashMap;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum UpgradeRequirement {
@@ -51,6 +52,96 @@ pub enum UpgradeEffect {
     // Stellar Essence / Cosmic Cat system
     StellarEssenceMultiplier(f64), // Multiplies the effect of stellar essence
     CosmicCatBonus(f64),           // E/s multiplied by (1 + stellar_essence * bonus)
+    // Signature mechanics for producers 3-20 (see signature.rs)
+    AsteroidProspecting {
+        bonus_per_hundred_clicks: f64,
+    }, // Asteroid Mines: + per 100 manual mines this run
+    OrbitalRelay {
+        bonus_per_type: f64,
+    }, // Orbital Stations: + per distinct producer type owned
+    LunarCycle {
+        amplitude: f64,
+        period_secs: u64,
+    }, // Lunar Colonies: output waxes and wanes on a cycle
+    HarvestIndex {
+        bonus_per_upgrade: f64,
+    }, // Planetary Harvesters: + per upgrade purchased
+    FusionRampUp {
+        bonus_per_minute: f64,
+        max_bonus: f64,
+    }, // Fusion Reactors: ramp up while running uninterrupted
+    SwarmCollection {
+        bonus_per_panel: f64,
+    }, // Dyson Swarms: + per Solar Panel owned
+    SphereShells {
+        bonus_per_shell: f64,
+    }, // Dyson Spheres: + per producer type with 100+ owned
+    ForgeOverdrive {
+        bonus: f64,
+    }, // Star Forges: burn excess energy for a temporary boost
+    NeutronDensity {
+        bonus_per_magnitude: f64,
+    }, // Neutron Harvesters: + per order of magnitude of all-time energy
+    BlackHoleAccretion {
+        bonus_per_hundred: f64,
+    }, // Black Hole Taps: + per 100 lower-tier buildings
+    VacuumZeroPoint {
+        bonus: f64,
+    }, // Quantum Vacuums: boost while banked energy is low
+    GalacticGravity {
+        bonus_per_core: f64,
+    }, // Galactic Cores: every core boosts all production
+    DarkMatterMass {
+        bonus_per_chip: f64,
+    }, // Dark Matter Engines: + per unspent Stellar Chip
+    CosmicStringWeave {
+        bonus_per_link: f64,
+    }, // Cosmic Strings: + per adjacent tier pair with 50+ each
+    MultiverseEchoes {
+        bonus_per_ascension: f64,
+    }, // Multiverse Gates: + per ascension
+    RealityCompilation {
+        bonus_per_achievement: f64,
+    }, // Reality Compilers: + per achievement unlocked
+    EntropyReversal {
+        bonus_per_hour: f64,
+        max_bonus: f64,
+    }, // Entropy Reversers: grow with time spent in this run
+    PrimordialSpark {
+        bonus_per_signature: f64,
+    }, // Big Bang Catalysts: + per signature upgrade purchased
+    SignatureAmplifier {
+        producer_id: u32,
+        multiplier: f64,
+    }, // Multiplies a producer's signature bonus
+}
+
+impl UpgradeEffect {
+    /// The producer whose signature mechanic this effect drives, if any
+    pub fn signature_producer(&self) -> Option<u32> {
+        match self {
+            UpgradeEffect::AsteroidProspecting { .. } => Some(3),
+            UpgradeEffect::OrbitalRelay { .. } => Some(4),
+            UpgradeEffect::LunarCycle { .. } => Some(5),
+            UpgradeEffect::HarvestIndex { .. } => Some(6),
+            UpgradeEffect::FusionRampUp { .. } => Some(7),
+            UpgradeEffect::SwarmCollection { .. } => Some(8),
+            UpgradeEffect::SphereShells { .. } => Some(9),
+            UpgradeEffect::ForgeOverdrive { .. } => Some(10),
+            UpgradeEffect::NeutronDensity { .. } => Some(11),
+            UpgradeEffect::BlackHoleAccretion { .. } => Some(12),
+            UpgradeEffect::VacuumZeroPoint { .. } => Some(13),
+            UpgradeEffect::GalacticGravity { .. } => Some(14),
+            UpgradeEffect::DarkMatterMass { .. } => Some(15),
+            UpgradeEffect::CosmicStringWeave { .. } => Some(16),
+            UpgradeEffect::MultiverseEchoes { .. } => Some(17),
+            UpgradeEffect::RealityCompilation { .. } => Some(18),
+            UpgradeEffect::EntropyReversal { .. } => Some(19),
+            UpgradeEffect::PrimordialSpark { .. } => Some(20),
+            UpgradeEffect::SignatureAmplifier { producer_id, .. } => Some(*producer_id),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -67,6 +158,25 @@ impl Upgrade {
     pub fn all() -> &'static [Upgrade] {
         &UPGRADES
     }
+
+    /// Look up an upgrade by id in constant time
+    pub fn by_id(id: u32) -> Option<&'static Upgrade> {
+        UPGRADE_INDEX.get(&id).map(|&index| &UPGRADES[index])
+    }
+}
+
+// Upgrade id -> position in UPGRADES, so lookups don't scan the whole table
+static UPGRADE_INDEX: Lazy<HashMap<u32, usize>> = Lazy::new(|| {
+    UPGRADES
+        .iter()
+        .enumerate()
+        .map(|(index, upgrade)| (upgrade.id, index))
+        .collect()
+});
+
+/// Id of a signature chain upgrade: 6000 + producer_id * 10 + tier (tiers start at 1)
+pub fn signature_upgrade_id(producer_id: u32, tier: u32) -> u32 {
+    6000 + producer_id * 10 + tier
 }
 
 // Unlock thresholds for 15 tiers
@@ -711,6 +821,189 @@ static UPGRADES: Lazy<Vec<Upgrade>> = Lazy::new(|| {
         });
     }
 
+    // ============ SIGNATURE MECHANICS (Producers 3-20) ============
+    // Each producer gets a 3-upgrade chain: the first unlocks its mechanic,
+    // the next two each double the mechanic's bonus
+    let signature_mechanics: [(u32, [&str; 3], &str, UpgradeEffect); 18] = [
+        (
+            3,
+            ["Prospector's Instinct", "Seismic Surveys", "Motherlode Sense"],
+            "Asteroid Mines gain +2% E/s per 100 manual mines this run.",
+            UpgradeEffect::AsteroidProspecting {
+                bonus_per_hundred_clicks: 0.02,
+            },
+        ),
+        (
+            4,
+            ["Relay Network", "Relay Mesh", "Relay Lattice"],
+            "Orbital Stations gain +5% E/s per producer type owned.",
+            UpgradeEffect::OrbitalRelay {
+                bonus_per_type: 0.05,
+            },
+        ),
+        (
+            5,
+            ["Lunar Cycle", "Tidal Harmonics", "Eclipse Surge"],
+            "Lunar Colony output waxes and wanes every 2 minutes, up to +100% E/s.",
+            UpgradeEffect::LunarCycle {
+                amplitude: 1.0,
+                period_secs: 120,
+            },
+        ),
+        (
+            6,
+            ["Harvest Index", "Yield Forecasting", "Harvest Futures"],
+            "Planetary Harvesters gain +0.5% E/s per upgrade purchased.",
+            UpgradeEffect::HarvestIndex {
+                bonus_per_upgrade: 0.005,
+            },
+        ),
+        (
+            7,
+            ["Sustained Fusion", "Plasma Stabilizers", "Eternal Burn"],
+            "Fusion Reactors gain +1% E/s per minute of uninterrupted running (max +100%). Selling one resets it.",
+            UpgradeEffect::FusionRampUp {
+                bonus_per_minute: 0.01,
+                max_bonus: 1.0,
+            },
+        ),
+        (
+            8,
+            ["Solar Collection", "Collector Alignment", "Total Capture"],
+            "Dyson Swarms gain +0.5% E/s per Solar Panel owned.",
+            UpgradeEffect::SwarmCollection {
+                bonus_per_panel: 0.005,
+            },
+        ),
+        (
+            9,
+            ["Completed Shells", "Shell Reinforcement", "Nested Spheres"],
+            "Dyson Spheres gain +10% E/s per producer type with at least 100 owned.",
+            UpgradeEffect::SphereShells {
+                bonus_per_shell: 0.1,
+            },
+        ),
+        (
+            10,
+            ["Stellar Overdrive", "Overdrive Coils", "Supernova Drive"],
+            "Star Forges burn 1% per second of energy above an hour of production for +100% E/s.",
+            UpgradeEffect::ForgeOverdrive { bonus: 1.0 },
+        ),
+        (
+            11,
+            ["Neutron Density", "Degenerate Matter", "Magnetar Focus"],
+            "Neutron Harvesters gain +5% E/s per order of magnitude of all-time energy.",
+            UpgradeEffect::NeutronDensity {
+                bonus_per_magnitude: 0.05,
+            },
+        ),
+        (
+            12,
+            ["Accretion Disk", "Tidal Disruption", "Hawking Feast"],
+            "Black Hole Taps gain +5% E/s per 100 lower-tier buildings owned.",
+            UpgradeEffect::BlackHoleAccretion {
+                bonus_per_hundred: 0.05,
+            },
+        ),
+        (
+            13,
+            ["Zero-Point Draw", "Casimir Plates", "False Vacuum"],
+            "Quantum Vacuums gain +100% E/s while banked energy is below a minute of production.",
+            UpgradeEffect::VacuumZeroPoint { bonus: 1.0 },
+        ),
+        (
+            14,
+            ["Galactic Gravity", "Spiral Arms", "Galactic Dominion"],
+            "All production gains +0.1% E/s per Galactic Core owned.",
+            UpgradeEffect::GalacticGravity {
+                bonus_per_core: 0.001,
+            },
+        ),
+        (
+            15,
+            ["Hidden Mass", "Dark Halo", "Shadow Ledger"],
+            "Dark Matter Engines gain +1% E/s per unspent Stellar Chip.",
+            UpgradeEffect::DarkMatterMass {
+                bonus_per_chip: 0.01,
+            },
+        ),
+        (
+            16,
+            ["String Weave", "Harmonic Braids", "Cosmic Loom"],
+            "Cosmic Strings gain +10% E/s per pair of adjacent producer tiers with 50+ owned each.",
+            UpgradeEffect::CosmicStringWeave {
+                bonus_per_link: 0.1,
+            },
+        ),
+        (
+            17,
+            ["Parallel Echoes", "Echo Chambers", "Infinite Mirrors"],
+            "Multiverse Gates gain +10% E/s per ascension.",
+            UpgradeEffect::MultiverseEchoes {
+                bonus_per_ascension: 0.1,
+            },
+        ),
+        (
+            18,
+            ["Compiled Achievements", "Optimizing Passes", "Self-Hosting"],
+            "Reality Compilers gain +1% E/s per achievement unlocked.",
+            UpgradeEffect::RealityCompilation {
+                bonus_per_achievement: 0.01,
+            },
+        ),
+        (
+            19,
+            ["Time Reversal", "Causal Loops", "Arrow Inversion"],
+            "Entropy Reversers gain +10% E/s per hour of this run (max +200%).",
+            UpgradeEffect::EntropyReversal {
+                bonus_per_hour: 0.1,
+                max_bonus: 2.0,
+            },
+        ),
+        (
+            20,
+            ["Primordial Spark", "Inflation Field", "First Light"],
+            "Big Bang Catalysts gain +10% E/s per signature upgrade purchased.",
+            UpgradeEffect::PrimordialSpark {
+                bonus_per_signature: 0.1,
+            },
+        ),
+    ];
+
+    // (required count, cost multiplier over the producer's first upgrade cost)
+    let signature_tiers: [(u64, f64); 3] = [(15, 30.0), (75, 3_000.0), (175, 300_000.0)];
+
+    for (producer_id, names, description, effect) in signature_mechanics {
+        let base_cost = PRODUCER_BASE_COSTS[(producer_id - 1) as usize];
+
+        for (tier, (count, cost_mult)) in signature_tiers.iter().enumerate() {
+            let (description, effect) = if tier == 0 {
+                (description, effect)
+            } else {
+                (
+                    Box::leak(format!("Doubles the bonus from {}.", names[0]).into_boxed_str())
+                        as &'static str,
+                    UpgradeEffect::SignatureAmplifier {
+                        producer_id,
+                        multiplier: 2.0,
+                    },
+                )
+            };
+
+            upgrades.push(Upgrade {
+                id: signature_upgrade_id(producer_id, tier as u32 + 1),
+                name: names[tier],
+                description,
+                cost: base_cost * cost_mult,
+                requirement: UpgradeRequirement::ProducerCount {
+                    producer_id,
+                    count: *count,
+                },
+                effect,
+            });
+        }
+    }
+
     // Generate synergy upgrades (38 total - each adjacent pair has 2 synergies, one in each direction)
     // Forward synergies: Building N boosts Building N+1
     // Reverse synergies: Building N+1 boosts Building N
@@ -0,0 +1,160 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use ratatui::symbols::border;
+
+use crate::game::Producer;
+
+/// Every non-ASCII character the UI draws, so `--ascii` can swap them all at once
+pub struct Glyphs {
+    /// Energy unit after costs and amounts
+    pub energy: &'static str,
+    pub sun: char,
+    /// Essence gauge label
+    pub moon: &'static str,
+    /// Dots along empty orbit paths
+    pub orbit: char,
+    /// Essence gauge fill, one per 100% layer
+    pub gauge: [char; 4],
+    pub bar_filled: &'static str,
+    pub bar_empty: &'static str,
+    /// Points at a milestone target in the producer list
+    pub arrow: &'static str,
+    /// Twinkle sequence for animated stars, dimmest first
+    pub twinkle: [char; 6],
+    /// Characters for stars that don't animate
+    pub still_stars: [char; 3],
+    /// Panel and popup borders
+    pub border: border::Set,
+}
+
+pub const UNICODE: Glyphs = Glyphs {
+    energy: "⚛",
+    sun: '☀',
+    moon: "☽",
+    orbit: '·',
+    gauge: ['█', '▓', '▒', '░'],
+    bar_filled: "█",
+    bar_empty: "░",
+    arrow: "→",
+    twinkle: ['·', '✢', '✳', '∗', '✻', '✽'],
+    still_stars: ['·', '∗', '✧'],
+    border: border::PLAIN,
+};
+
+pub const ASCII: Glyphs = Glyphs {
+    energy: "E",
+    sun: 'O',
+    moon: "C",
+    orbit: '.',
+    gauge: ['#', '=', '-', '.'],
+    bar_filled: "#",
+    bar_empty: "-",
+    arrow: "->",
+    twinkle: ['.', '.', '+', '+', '*', '*'],
+    still_stars: ['.', '+', '*'],
+    border: border::Set {
+        top_left: "+",
+        top_right: "+",
+        bottom_left: "+",
+        bottom_right: "+",
+        vertical_left: "|",
+        vertical_right: "|",
+        horizontal_top: "-",
+        horizontal_bottom: "-",
+    },
+};
+
+static ASCII_MODE: AtomicBool = AtomicBool::new(false);
+
+pub fn set_ascii(enabled: bool) {
+    ASCII_MODE.store(enabled, Ordering::Relaxed);
+}
+
+pub fn is_ascii() -> bool {
+    ASCII_MODE.load(Ordering::Relaxed)
+}
+
+/// The glyph set for the current mode
+pub fn glyphs() -> &'static Glyphs {
+    if is_ascii() {
+        &ASCII
+    } else {
+        &UNICODE
+    }
+}
+
+/// A producer's icon in the current mode
+pub fn icon(producer: &Producer) -> &'static str {
+    if is_ascii() {
+        producer.ascii_icon
+    } else {
+        producer.icon
+    }
+}
+
+/// Whether the locale lacks UTF-8, going by the first of `LC_ALL`,
+/// `LC_CTYPE` and `LANG` that is set, the same order the C library uses.
+/// An unset locale means "C", which is ASCII.
+pub fn locale_is_ascii() -> bool {
+    if cfg!(windows) {
+        return false;
+    }
+    let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|value| !value.is_empty());
+    match locale {
+        Some(locale) => !is_utf8_locale(&locale),
+        None => true,
+    }
+}
+
+fn is_utf8_locale(locale: &str) -> bool {
+    let locale = locale.to_ascii_lowercase();
+    locale.contains("utf-8") || locale.contains("utf8")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_utf8_locale_detection() {
+        assert!(is_utf8_locale("en_US.UTF-8"));
+        assert!(is_utf8_locale("de_DE.utf8"));
+        assert!(!is_utf8_locale("C"));
+        assert!(!is_utf8_locale("POSIX"));
+        assert!(!is_utf8_locale("en_US.ISO-8859-1"));
+    }
+
+    #[test]
+    fn test_ascii_glyphs_are_ascii() {
+        let strings = [
+            ASCII.energy,
+            ASCII.moon,
+            ASCII.bar_filled,
+            ASCII.bar_empty,
+            ASCII.arrow,
+        ];
+        assert!(strings.iter().all(|s| s.is_ascii()));
+        let chars = [ASCII.sun, ASCII.orbit]
+            .into_iter()
+            .chain(ASCII.gauge)
+            .chain(ASCII.twinkle)
+            .chain(ASCII.still_stars);
+        assert!(chars.into_iter().all(|c| c.is_ascii()));
+    }
+
+    #[test]
+    fn test_producer_ascii_icons() {
+        for producer in Producer::all() {
+            assert_eq!(
+                producer.ascii_icon.len(),
+                1,
+                "{} needs a one-character ASCII icon",
+                producer.name
+            );
+            assert!(producer.ascii_icon.is_ascii());
+        }
+    }
+}
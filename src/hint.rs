@@ -93,28 +93,67 @@ pub fn generate_hint(app: &App) -> HintResult {
     }
 }
 
+/// The purchase the hint recommends, for `solaris status`
+pub struct NextPurchase {
+    pub name: &'static str,
+    /// "producer" or "upgrade"
+    pub kind: &'static str,
+    pub cost: f64,
+    /// Seconds of saving at the current rate; zero if affordable now
+    pub wait_secs: f64,
+}
+
+/// What the hint would recommend buying next, using the bot's decision logic
+pub fn next_purchase(app: &App) -> Option<NextPurchase> {
+    let (target, cost) = match decide_best_action(app) {
+        DecisionResult::Buy(target) | DecisionResult::UnlockProducer(target) => (target, None),
+        DecisionResult::Wait { save_for, .. } => (save_for.target, Some(save_for.cost)),
+        DecisionResult::Nothing => return None,
+    };
+    let (name, kind, target_cost) = resolve_target(app, &target)?;
+    let cost = cost.unwrap_or(target_cost);
+
+    let energy_needed = (cost - app.game.energy).max(0.0);
+    let current_eps = app.game.total_energy_per_second();
+    let wait_secs = if energy_needed == 0.0 {
+        0.0
+    } else if current_eps > 0.0 {
+        energy_needed / current_eps
+    } else {
+        f64::INFINITY
+    };
+
+    Some(NextPurchase {
+        name,
+        kind,
+        cost,
+        wait_secs,
+    })
+}
+
 /// Resolve the name and cost of a target (producer or upgrade).
 fn resolve_target_info(app: &App, target: &AutoTarget) -> (String, f64) {
+    match (resolve_target(app, target), target) {
+        (Some((name, kind, cost)), _) => (format!("{} ({})", name, kind), cost),
+        (None, AutoTarget::Producer { .. }) => ("Unknown producer".to_string(), 0.0),
+        (None, AutoTarget::Upgrade { .. }) => ("Unknown upgrade".to_string(), 0.0),
+    }
+}
+
+/// Name, kind and next-unit cost of a target
+fn resolve_target(app: &App, target: &AutoTarget) -> Option<(&'static str, &'static str, f64)> {
     match target {
         AutoTarget::Producer { index } => {
             let visible = app.game.visible_producers();
-            if let Some((_, producer)) = visible.get(*index) {
-                let owned = app.game.producer_count(producer.id);
-                let cost =
-                    crate::game::calculate_bulk_cost(producer.base_cost, owned, 1, producer.id);
-                (format!("{} (producer)", producer.name), cost)
-            } else {
-                ("Unknown producer".to_string(), 0.0)
-            }
+            let (_, producer) = visible.get(*index)?;
+            let owned = app.game.producer_count(producer.id);
+            let cost = crate::game::calculate_bulk_cost(producer.base_cost, owned, 1, producer.id);
+            Some((producer.name, "producer", cost))
         }
         AutoTarget::Upgrade { index } => {
             let available = app.game.available_upgrades();
-            if let Some(upgrade) = available.get(*index) {
-                let cost = app.game.get_upgrade_cost(upgrade);
-                (format!("{} (upgrade)", upgrade.name), cost)
-            } else {
-                ("Unknown upgrade".to_string(), 0.0)
-            }
+            let upgrade = available.get(*index)?;
+            Some((upgrade.name, "upgrade", app.game.get_upgrade_cost(upgrade)))
         }
     }
 }
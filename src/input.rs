@@ -1,6 +1,6 @@
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
-use crate::app::{App, LayoutMode, Panel};
+use crate::app::{App, Panel, SavePrompt};
 use crate::ui::filtered_achievement_count;
 
 /// Handle a key event, returns true if the app should quit
@@ -22,6 +22,19 @@ pub fn handle_key(app: &mut App, key: KeyEvent) -> bool {
         return false;
     }
 
+    // Custom buy amount prompt captures typing until confirmed or cancelled
+    if app.buy_amount_input.is_some() {
+        match key.code {
+            KeyCode::Char(c) if c.is_ascii_digit() => app.push_buy_amount_digit(c),
+            KeyCode::Backspace => app.pop_buy_amount_digit(),
+            KeyCode::Enter => app.confirm_custom_buy_amount(),
+            KeyCode::Esc => app.cancel_custom_buy_amount(),
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return true,
+            _ => {}
+        }
+        return false;
+    }
+
     // Help toggle
     if key.code == KeyCode::Char('?') {
         app.toggle_help();
@@ -56,17 +69,78 @@ pub fn handle_key(app: &mut App, key: KeyEvent) -> bool {
         match key.code {
             KeyCode::Char('x') | KeyCode::Char('q') | KeyCode::Esc => app.toggle_achievements(),
             KeyCode::Tab => app.cycle_achievement_tab(),
+            KeyCode::Char('s') => app.toggle_achievement_sort(),
+            // Number keys jump to a tab: 1 = All, 2.. = categories
+            KeyCode::Char(c @ '1'..='9') => {
+                app.select_achievement_tab(c as usize - '1' as usize);
+            }
             KeyCode::Char('j') | KeyCode::Down => {
                 let max = filtered_achievement_count(app).saturating_sub(1);
                 if app.selected_achievement < max {
                     app.selected_achievement += 1;
                 }
             }
-            KeyCode::Char('k') | KeyCode::Up => {
-                if app.selected_achievement > 0 {
-                    app.selected_achievement -= 1;
-                }
+            KeyCode::Char('k') | KeyCode::Up if app.selected_achievement > 0 => {
+                app.selected_achievement -= 1;
+            }
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return true,
+            _ => {}
+        }
+        return false;
+    }
+
+    // When event log is shown
+    if app.show_log {
+        match key.code {
+            KeyCode::Char('L') | KeyCode::Esc => app.toggle_log(),
+            KeyCode::Tab => app.cycle_log_filter(),
+            KeyCode::Char('j') | KeyCode::Down => app.move_log_selection_down(),
+            KeyCode::Char('k') | KeyCode::Up => app.move_log_selection_up(),
+            KeyCode::Char('q') => return true,
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return true,
+            _ => {}
+        }
+        return false;
+    }
+
+    // When the save manager is shown
+    if app.show_saves {
+        match (&app.save_prompt, key.code) {
+            (_, KeyCode::Char('c')) if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                return true
             }
+            (Some(SavePrompt::ConfirmDelete), KeyCode::Char('y')) => app.confirm_save_prompt(),
+            (Some(SavePrompt::ConfirmDelete), _) => app.cancel_save_prompt(),
+            (Some(_), KeyCode::Char(c)) => app.push_save_prompt_char(c),
+            (Some(_), KeyCode::Backspace) => app.pop_save_prompt_char(),
+            (Some(_), KeyCode::Enter) => app.confirm_save_prompt(),
+            (Some(_), KeyCode::Esc) => app.cancel_save_prompt(),
+            (Some(_), _) => {}
+            (None, KeyCode::Char('o') | KeyCode::Esc) => app.toggle_saves(),
+            (None, KeyCode::Char('j') | KeyCode::Down) => app.move_save_selection_down(),
+            (None, KeyCode::Char('k') | KeyCode::Up) => app.move_save_selection_up(),
+            (None, KeyCode::Enter) => app.switch_to_selected_save(),
+            (None, KeyCode::Char('c')) => app.start_duplicate_save(),
+            (None, KeyCode::Char('r')) => app.start_rename_save(),
+            (None, KeyCode::Char('D') | KeyCode::Delete) => app.start_delete_save(),
+            (None, KeyCode::Char('q')) => return true,
+            _ => {}
+        }
+        return false;
+    }
+
+    // When purchase queue is shown
+    if app.show_queue {
+        match key.code {
+            KeyCode::Char('w') | KeyCode::Esc => app.toggle_queue(),
+            KeyCode::Char('j') | KeyCode::Down => app.move_queue_selection_down(),
+            KeyCode::Char('k') | KeyCode::Up => app.move_queue_selection_up(),
+            KeyCode::Char('J') => app.move_queued_item_down(),
+            KeyCode::Char('K') => app.move_queued_item_up(),
+            KeyCode::Char('d') | KeyCode::Delete | KeyCode::Backspace => {
+                app.remove_selected_queue_item()
+            }
+            KeyCode::Char('q') => return true,
             KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return true,
             _ => {}
         }
@@ -91,36 +165,32 @@ pub fn handle_key(app: &mut App, key: KeyEvent) -> bool {
 
         // Buy amount cycling
         KeyCode::Tab => app.cycle_buy_amount(),
+        KeyCode::Char('n') => app.start_custom_buy_amount(),
+
+        // Sell mode toggle
+        KeyCode::Char('S') => app.toggle_sell_mode(),
 
         // Panel focus/toggle
         KeyCode::Char('p') => {
-            if app.layout_mode == LayoutMode::TwoColumn {
+            if app.layout_mode.is_multi_column() {
                 app.focus_panel(Panel::Producers);
             } else {
                 app.toggle_panel(Panel::Producers);
             }
         }
         KeyCode::Char('u') => {
-            if app.layout_mode == LayoutMode::TwoColumn {
+            if app.layout_mode.is_multi_column() {
                 app.focus_panel(Panel::Upgrades);
             } else {
                 app.toggle_panel(Panel::Upgrades);
             }
         }
-        KeyCode::Char('s') => {
-            if app.layout_mode == LayoutMode::TwoColumn {
-                // Stats not visible in two-column mode, stay on current panel
-            } else {
-                app.toggle_panel(Panel::Stats);
-            }
-        }
-        KeyCode::Char('v') => {
-            if app.layout_mode == LayoutMode::TwoColumn {
-                app.focus_panel(Panel::Visualization);
-            } else {
-                app.toggle_panel(Panel::Visualization);
-            }
-        }
+        KeyCode::Char('s') => app.show_stats(),
+        KeyCode::Char('v') => app.show_visualization(),
+
+        // Column split
+        KeyCode::Char('[') if app.layout_mode.is_multi_column() => app.adjust_column_split(-5),
+        KeyCode::Char(']') if app.layout_mode.is_multi_column() => app.adjust_column_split(5),
 
         // Prestige/Ascension panel
         KeyCode::Char('a') => app.toggle_prestige(),
@@ -128,11 +198,19 @@ pub fn handle_key(app: &mut App, key: KeyEvent) -> bool {
         // Achievements panel
         KeyCode::Char('x') => app.toggle_achievements(),
 
+        // Purchase queue
+        KeyCode::Char('e') => app.enqueue_selected(),
+        KeyCode::Char('w') => app.toggle_queue(),
+
+        // Event log
+        KeyCode::Char('L') => app.toggle_log(),
+
+        // Save manager
+        KeyCode::Char('o') => app.toggle_saves(),
+
         // Producer detail toggle (only when Producers panel is focused)
-        KeyCode::Char('d') => {
-            if app.active_panel == Panel::Producers {
-                app.toggle_producer_detail();
-            }
+        KeyCode::Char('d') if app.active_panel == Panel::Producers => {
+            app.toggle_producer_detail();
         }
 
         // Purchase hint
@@ -0,0 +1,9 @@
+//! The game logic behind the `solaris` binary: producers, upgrades,
+//! achievements, prestige and the economy that ties them together.
+//! The terminal UI, saves and daemon live in the binary.
+
+pub mod clock;
+pub mod game;
+
+pub const TICK_RATE_MS: u64 = 100; // 10 ticks/second for game logic
+pub const TICKS_PER_SECOND: f64 = 1000.0 / TICK_RATE_MS as f64;
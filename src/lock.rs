@@ -0,0 +1,313 @@
+use std::fmt;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Local, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::save::{self, get_lock_path};
+
+/// Which process has a save slot open, as written in its lock file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LockOwner {
+    pub pid: u32,
+    pub since: DateTime<Utc>,
+}
+
+impl LockOwner {
+    fn current() -> Self {
+        Self {
+            pid: std::process::id(),
+            since: Utc::now(),
+        }
+    }
+}
+
+impl fmt::Display for LockOwner {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "pid {}, since {}",
+            self.pid,
+            self.since.with_timezone(&Local).format("%Y-%m-%d %H:%M")
+        )
+    }
+}
+
+#[derive(Debug)]
+pub enum LockError {
+    /// Another running instance has the slot open
+    Held(LockOwner),
+    Io(io::Error),
+}
+
+impl From<io::Error> for LockError {
+    fn from(e: io::Error) -> Self {
+        LockError::Io(e)
+    }
+}
+
+/// Advisory lock on a save slot, held while the game has it open so two
+/// instances don't overwrite each other's progress. The lock file is
+/// `saves/<label>.lock` and is removed again on drop.
+#[derive(Debug)]
+pub struct SaveLock {
+    path: PathBuf,
+}
+
+impl SaveLock {
+    /// Lock a slot, clearing the lock of an instance that is no longer running
+    pub fn acquire(label: &str) -> Result<Self, LockError> {
+        Self::acquire_at(get_lock_path(label)?)
+    }
+
+    fn acquire_at(path: PathBuf) -> Result<Self, LockError> {
+        // Instances take turns, so two can't both judge the same lock stale
+        // and one remove the lock the other has just taken in its place
+        let _guard = lock_guard(&path)?;
+        // A second attempt only happens after removing a stale lock
+        for _ in 0..2 {
+            // Link a fully written file into place, so nobody ever reads a
+            // lock file that exists but doesn't name its owner yet
+            let temp = write_temp_owner(&path)?;
+            let linked = fs::hard_link(&temp, &path);
+            let _ = fs::remove_file(&temp);
+            match linked {
+                Ok(()) => return Ok(Self { path }),
+                Err(e) if e.kind() == io::ErrorKind::AlreadyExists => match read_owner(&path)? {
+                    Some(owner) if is_running(owner.pid) => return Err(LockError::Held(owner)),
+                    _ => remove_if_exists(&path)?,
+                },
+                Err(e) => return Err(e.into()),
+            }
+        }
+        match read_owner(&path)? {
+            Some(owner) => Err(LockError::Held(owner)),
+            None => Err(io::Error::other("Could not lock the save").into()),
+        }
+    }
+
+    /// Lock a slot even if another instance has it open. That instance
+    /// notices on its next save and stops saving.
+    pub fn take_over(label: &str) -> io::Result<Self> {
+        Self::take_over_at(get_lock_path(label)?)
+    }
+
+    fn take_over_at(path: PathBuf) -> io::Result<Self> {
+        let _guard = lock_guard(&path)?;
+        let temp = write_temp_owner(&path)?;
+        if let Err(e) = fs::rename(&temp, &path) {
+            let _ = fs::remove_file(&temp);
+            return Err(e);
+        }
+        Ok(Self { path })
+    }
+
+    /// Whether the lock file still names this process, i.e. nobody took over
+    pub fn is_held(&self) -> bool {
+        matches!(read_owner(&self.path), Ok(Some(owner)) if owner.pid == std::process::id())
+    }
+}
+
+impl Drop for SaveLock {
+    fn drop(&mut self) {
+        // Leave the file alone if another instance took over
+        if self.is_held() {
+            let _ = fs::remove_file(&self.path);
+        }
+    }
+}
+
+/// The other running instance that has a slot open, if any
+pub fn held_elsewhere(label: &str) -> io::Result<Option<LockOwner>> {
+    let owner = read_owner(&get_lock_path(label)?)?;
+    Ok(owner.filter(|owner| owner.pid != std::process::id() && is_running(owner.pid)))
+}
+
+/// The error for touching a slot another instance has open
+pub fn held_error(label: &str, owner: &LockOwner) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::WouldBlock,
+        format!("Save '{}' is open in another instance ({})", label, owner),
+    )
+}
+
+fn write_owner(file: &mut fs::File, owner: &LockOwner) -> io::Result<()> {
+    let json =
+        serde_json::to_string(owner).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    file.write_all(json.as_bytes())?;
+    file.sync_all()
+}
+
+/// Exclusive use of the lock files in `path`'s directory until the
+/// returned file is closed. The OS releases it if the process dies.
+fn lock_guard(path: &Path) -> io::Result<fs::File> {
+    let guard = fs::OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(path.with_file_name(".lock-guard"))?;
+    guard.lock()?;
+    Ok(guard)
+}
+
+/// Write this process as the owner to a file next to `path`, to be moved
+/// into place once complete
+fn write_temp_owner(path: &Path) -> io::Result<PathBuf> {
+    let temp = save::temp_path(path);
+    let mut file = fs::File::create(&temp)?;
+    write_owner(&mut file, &LockOwner::current())?;
+    Ok(temp)
+}
+
+/// `None` if there's no lock file or it can't be parsed, which counts as stale
+fn read_owner(path: &Path) -> io::Result<Option<LockOwner>> {
+    match fs::read_to_string(path) {
+        Ok(json) => Ok(serde_json::from_str(&json).ok()),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+fn remove_if_exists(path: &Path) -> io::Result<()> {
+    match fs::remove_file(path) {
+        Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+        _ => Ok(()),
+    }
+}
+
+/// Whether a process with this PID exists. Where that can't be checked the
+/// lock is assumed live, so a stale lock needs `--take-over` rather than
+/// two instances sharing a slot.
+fn is_running(pid: u32) -> bool {
+    if pid == std::process::id() {
+        return true;
+    }
+    if cfg!(target_os = "linux") {
+        return PathBuf::from("/proc").join(pid.to_string()).exists();
+    }
+    if cfg!(unix) {
+        return std::process::Command::new("kill")
+            .args(["-0", &pid.to_string()])
+            .stderr(std::process::Stdio::null())
+            .status()
+            .map_or(true, |status| status.success());
+    }
+    if cfg!(windows) {
+        return std::process::Command::new("tasklist")
+            .args(["/FI", &format!("PID eq {}", pid), "/NH"])
+            .output()
+            .map_or(true, |out| {
+                String::from_utf8_lossy(&out.stdout).contains(&pid.to_string())
+            });
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Lock and temp files in `dir`, leaving out the guard
+    fn lock_files(dir: &Path) -> usize {
+        fs::read_dir(dir)
+            .unwrap()
+            .filter(|entry| entry.as_ref().unwrap().file_name() != ".lock-guard")
+            .count()
+    }
+
+    #[test]
+    fn test_current_process_is_running() {
+        assert!(is_running(std::process::id()));
+    }
+
+    #[test]
+    fn test_stale_and_garbled_lock_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("main.lock");
+
+        assert_eq!(read_owner(&path).unwrap(), None);
+
+        fs::write(&path, "not json").unwrap();
+        assert_eq!(read_owner(&path).unwrap(), None);
+
+        let mut file = fs::File::create(&path).unwrap();
+        let owner = LockOwner::current();
+        write_owner(&mut file, &owner).unwrap();
+        assert_eq!(read_owner(&path).unwrap(), Some(owner));
+
+        remove_if_exists(&path).unwrap();
+        remove_if_exists(&path).unwrap();
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_acquire_and_take_over() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("main.lock");
+
+        let lock = SaveLock::acquire_at(path.clone()).unwrap();
+        assert!(lock.is_held());
+        assert!(matches!(
+            SaveLock::acquire_at(path.clone()),
+            Err(LockError::Held(owner)) if owner.pid == std::process::id()
+        ));
+        drop(lock);
+        assert!(!path.exists());
+
+        // A lock left by a process that is gone is cleared
+        let dead = LockOwner {
+            pid: u32::MAX,
+            since: Utc::now(),
+        };
+        fs::write(&path, serde_json::to_string(&dead).unwrap()).unwrap();
+        let lock = SaveLock::acquire_at(path.clone()).unwrap();
+        let taken = SaveLock::take_over_at(path.clone()).unwrap();
+        assert!(taken.is_held());
+        drop((lock, taken));
+
+        // No lock or temp file is left behind
+        assert!(!path.exists());
+        assert_eq!(lock_files(dir.path()), 0);
+    }
+
+    #[test]
+    fn test_racing_acquires_have_one_winner() {
+        // Losers must never see a half-written lock file and call it stale
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("main.lock");
+        for _ in 0..20 {
+            let locks: Vec<_> = std::thread::scope(|scope| {
+                let attempts: Vec<_> = (0..8)
+                    .map(|_| scope.spawn(|| SaveLock::acquire_at(path.clone())))
+                    .collect();
+                attempts.into_iter().map(|a| a.join().unwrap()).collect()
+            });
+            assert_eq!(locks.iter().filter(|lock| lock.is_ok()).count(), 1);
+        }
+    }
+
+    #[test]
+    fn test_racing_acquires_of_a_stale_lock_have_one_winner() {
+        // Clearing the dead instance's lock must not clear the winner's too
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("main.lock");
+        let dead = LockOwner {
+            pid: u32::MAX,
+            since: Utc::now(),
+        };
+        for _ in 0..20 {
+            fs::write(&path, serde_json::to_string(&dead).unwrap()).unwrap();
+            let locks: Vec<_> = std::thread::scope(|scope| {
+                let attempts: Vec<_> = (0..8)
+                    .map(|_| scope.spawn(|| SaveLock::acquire_at(path.clone())))
+                    .collect();
+                attempts.into_iter().map(|a| a.join().unwrap()).collect()
+            });
+            assert_eq!(locks.iter().filter(|lock| lock.is_ok()).count(), 1);
+            drop(locks);
+            assert_eq!(lock_files(dir.path()), 0);
+        }
+    }
+}
@@ -1,38 +1,89 @@
+mod api;
 mod app;
 mod auto;
+#[cfg(test)]
+mod bench;
+#[cfg(unix)]
+mod daemon;
 mod format;
-mod game;
+mod glyphs;
 mod hint;
 mod input;
+mod lock;
+mod replay;
 mod save;
+mod settings;
+mod status;
+mod theme;
 mod ui;
 
 use std::io::{self, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
-use chrono::{DateTime, Utc};
 use crossterm::{
+    cursor,
     event::{self, DisableMouseCapture, EnableMouseCapture},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use ratatui::prelude::*;
+use solaris::{clock, game, TICK_RATE_MS};
 
+use api::ApiServer;
 use app::App;
 use auto::AutoPlayer;
+use clock::TickClock;
+use lock::{LockError, SaveLock};
+use replay::Recorder;
 
-pub const TICK_RATE_MS: u64 = 100; // 10 ticks/second for game logic
-pub const TICKS_PER_SECOND: f64 = 1000.0 / TICK_RATE_MS as f64;
-const FRAME_RATE_MS: u64 = 16; // ~60 FPS for rendering
-const AUTOSAVE_INTERVAL_SECS: u64 = 30;
+const DEFAULT_FPS: u32 = 60;
+const MAX_FPS: u32 = 120;
+const DEFAULT_AUTOSAVE_SECS: u64 = 30;
+const MIN_AUTOSAVE_SECS: u64 = 5;
+const MAX_AUTOSAVE_SECS: u64 = 3600;
 
 fn main() -> io::Result<()> {
     // Handle command line arguments
     let args: Vec<String> = std::env::args().collect();
+    match args.get(1).map(String::as_str) {
+        Some("status") => {
+            glyphs::set_ascii(glyphs::locale_is_ascii());
+            return status::run(&args[2..]);
+        }
+        Some("replay") => {
+            glyphs::set_ascii(glyphs::locale_is_ascii());
+            return replay::run(&args[2..]);
+        }
+        #[cfg(unix)]
+        Some("daemon") => return daemon::run(&args[2..]),
+        #[cfg(unix)]
+        Some("attach") => return daemon::attach::run(&args[2..]),
+        #[cfg(not(unix))]
+        Some("daemon" | "attach") => {
+            eprintln!("Error: The daemon needs Unix domain sockets, which this platform lacks");
+            return Ok(());
+        }
+        _ => {}
+    }
     let mut auto_mode = false;
     let mut auto_speed: f64 = 1.0;
     let mut explicit_label: Option<String> = None;
     let mut create_new: Option<String> = None;
+    let mut theme_name: Option<String> = None;
+    let mut ascii: Option<bool> = None;
+    let mut fps = DEFAULT_FPS;
+    let mut reduced_motion = false;
+    let mut list = false;
+    let mut json = false;
+    let mut read_only = false;
+    let mut take_over = false;
+    let mut autosave_secs: Option<u64> = None;
+    let mut api_port: Option<u16> = None;
+    let mut record_path: Option<PathBuf> = None;
 
     let mut i = 1;
     while i < args.len() {
@@ -48,7 +99,10 @@ fn main() -> io::Result<()> {
                 return handle_delete(label);
             }
             "--list" => {
-                return handle_list();
+                list = true;
+            }
+            "--json" => {
+                json = true;
             }
             "--new" => {
                 i += 1;
@@ -78,6 +132,12 @@ fn main() -> io::Result<()> {
                     }
                 }
             }
+            "--read-only" => {
+                read_only = true;
+            }
+            "--take-over" => {
+                take_over = true;
+            }
             "--help" | "-h" => {
                 print_help();
                 return Ok(());
@@ -85,6 +145,79 @@ fn main() -> io::Result<()> {
             "--auto" => {
                 auto_mode = true;
             }
+            "--fps" => {
+                i += 1;
+                if i >= args.len() {
+                    eprintln!("Error: --fps requires a number (e.g. --fps 10)");
+                    return Ok(());
+                }
+                match args[i].parse::<u32>() {
+                    Ok(v) if (1..=MAX_FPS).contains(&v) => fps = v,
+                    _ => {
+                        eprintln!("Error: --fps value must be between 1 and {}", MAX_FPS);
+                        return Ok(());
+                    }
+                }
+            }
+            "--autosave" => {
+                i += 1;
+                if i >= args.len() {
+                    eprintln!(
+                        "Error: --autosave requires a number of seconds (e.g. --autosave 10)"
+                    );
+                    return Ok(());
+                }
+                match args[i].parse::<u64>() {
+                    Ok(v) if (MIN_AUTOSAVE_SECS..=MAX_AUTOSAVE_SECS).contains(&v) => {
+                        autosave_secs = Some(v)
+                    }
+                    _ => {
+                        eprintln!(
+                            "Error: --autosave value must be between {} and {} seconds",
+                            MIN_AUTOSAVE_SECS, MAX_AUTOSAVE_SECS
+                        );
+                        return Ok(());
+                    }
+                }
+            }
+            "--api-port" => {
+                i += 1;
+                match args.get(i).and_then(|v| v.parse::<u16>().ok()) {
+                    Some(port) => api_port = Some(port),
+                    None => {
+                        eprintln!("Error: --api-port requires a port number (0 picks a free one)");
+                        return Ok(());
+                    }
+                }
+            }
+            "--record" => {
+                i += 1;
+                if i >= args.len() {
+                    eprintln!("Error: --record requires a file (e.g. --record session.jsonl)");
+                    return Ok(());
+                }
+                record_path = Some(PathBuf::from(&args[i]));
+            }
+            "--no-animation" => {
+                reduced_motion = true;
+            }
+            "--ascii" => {
+                ascii = Some(true);
+            }
+            "--unicode" => {
+                ascii = Some(false);
+            }
+            "--theme" => {
+                i += 1;
+                if i >= args.len() {
+                    eprintln!(
+                        "Error: --theme requires a name ({})",
+                        theme::PRESETS.join(", ")
+                    );
+                    return Ok(());
+                }
+                theme_name = Some(args[i].clone());
+            }
             "--speed" => {
                 i += 1;
                 if i >= args.len() {
@@ -110,6 +243,26 @@ fn main() -> io::Result<()> {
         i += 1;
     }
 
+    // Without a flag, fall back to ASCII when the locale can't display Unicode
+    glyphs::set_ascii(ascii.unwrap_or_else(glyphs::locale_is_ascii));
+
+    if list {
+        return handle_list(json);
+    }
+    if json {
+        eprintln!("Error: --json only applies to --list");
+        return Ok(());
+    }
+
+    if read_only && take_over {
+        eprintln!("Error: --read-only and --take-over can't be used together");
+        return Ok(());
+    }
+    if read_only && create_new.is_some() {
+        eprintln!("Error: --read-only can't be used with --new");
+        return Ok(());
+    }
+
     // --speed implies --auto
     if auto_speed != 1.0 {
         auto_mode = true;
@@ -147,10 +300,87 @@ fn main() -> io::Result<()> {
         save::resolve_save_label(None)?.unwrap_or_else(|| "main".to_string())
     };
 
+    // Lock the slot so a second instance can't overwrite its progress
+    let save_lock = if read_only {
+        None
+    } else if take_over {
+        Some(SaveLock::take_over(&save_label)?)
+    } else {
+        match SaveLock::acquire(&save_label) {
+            Ok(lock) => Some(lock),
+            Err(LockError::Held(owner)) => {
+                eprintln!(
+                    "Error: Save '{}' is already open in another instance ({}).",
+                    save_label, owner
+                );
+                eprintln!();
+                #[cfg(unix)]
+                if save::get_socket_path(&save_label)?.exists() {
+                    eprintln!("  solaris attach --label {}", save_label);
+                    eprintln!("      It's a daemon; show it here");
+                }
+                eprintln!("  solaris --load {} --read-only", save_label);
+                eprintln!("      Watch it without saving anything");
+                eprintln!("  solaris --load {} --take-over", save_label);
+                eprintln!("      Open it here; the other instance stops saving");
+                return Ok(());
+            }
+            Err(LockError::Io(e)) => return Err(e),
+        }
+    };
+
     // Create app
     let mut app = App::new(save_label);
+    app.save_lock = save_lock;
+    app.read_only = read_only;
     app.auto_mode = auto_mode;
     app.auto_speed = auto_speed;
+    app.reduced_motion = reduced_motion;
+    match settings::load_settings() {
+        Ok(settings) => app.settings = settings,
+        Err(e) => eprintln!("Warning: Could not load settings: {}", e),
+    }
+    let theme_name = theme_name
+        .or_else(|| app.settings.theme.clone())
+        .unwrap_or_else(|| "default".to_string());
+    match theme::Theme::load(&theme_name) {
+        Ok(theme) => app.theme = theme,
+        Err(e) => eprintln!("Warning: Could not load theme: {}", e),
+    }
+    let autosave_secs = autosave_secs
+        .or(app.settings.autosave_secs)
+        .unwrap_or(DEFAULT_AUTOSAVE_SECS)
+        .clamp(MIN_AUTOSAVE_SECS, MAX_AUTOSAVE_SECS);
+
+    // A recording keeps the save as it is now and times everything by ticks,
+    // so a replay sees the same offline progress and timestamps
+    let mut recorder = None;
+    if let Some(path) = &record_path {
+        let started = chrono::Utc::now();
+        clock::set(TickClock::new(started));
+        let initial_save = if is_new_save {
+            None
+        } else {
+            save::read_save(&app.save_label)?
+        };
+        match Recorder::create(
+            path,
+            &app.save_label,
+            started,
+            initial_save,
+            auto_mode.then_some(auto_speed),
+        ) {
+            Ok(created) => recorder = Some(created),
+            Err(e) => {
+                eprintln!(
+                    "Error: Could not create recording '{}': {}",
+                    path.display(),
+                    e
+                );
+                return Ok(());
+            }
+        }
+    }
 
     // Load saved game if exists (for existing saves), or save immediately for new saves
     if is_new_save {
@@ -162,12 +392,16 @@ fn main() -> io::Result<()> {
         eprintln!("Warning: Could not load save file: {}", e);
     }
 
-    // Setup terminal
-    enable_raw_mode()?;
-    let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
-    let backend = CrosstermBackend::new(stdout);
-    let mut terminal = Terminal::new(backend)?;
+    let api = match start_api(api_port.or(app.settings.api_port)) {
+        Ok(api) => api,
+        Err(e) => {
+            eprintln!("Error: Could not start the API: {}", e);
+            return Ok(());
+        }
+    };
+
+    let shutdown = register_shutdown_signals(true)?;
+    let mut terminal = setup_terminal()?;
 
     let mut auto_player = if auto_mode {
         Some(AutoPlayer::new(auto_speed))
@@ -175,79 +409,240 @@ fn main() -> io::Result<()> {
         None
     };
 
-    let result = run_app(&mut terminal, &mut app, &mut auto_player);
+    let settings = RunSettings {
+        fps,
+        autosave_interval: Duration::from_secs(autosave_secs),
+        shutdown: &shutdown,
+        api: api.as_ref(),
+    };
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        run_app(
+            &mut terminal,
+            &mut app,
+            &mut auto_player,
+            &mut recorder,
+            &settings,
+        )
+    }));
+
+    // Restore terminal, but save and finish the recording before reporting
+    // a failure: after SIGHUP the terminal is gone and restoring fails too
+    let restored = restore_terminal();
+
+    if let (Some(recorder), Some(path)) = (recorder.take(), &record_path) {
+        match recorder.finish(&app.game) {
+            Ok(()) => println!(
+                "Recorded to {}. Replay it with: solaris replay {}",
+                path.display(),
+                path.display()
+            ),
+            Err(e) => eprintln!("Error: The recording is incomplete: {}", e),
+        }
+    }
+
+    match result {
+        Ok(Ok(())) => {}
+        Ok(Err(err)) => {
+            // Drawing fails once the terminal is gone, e.g. after SIGHUP
+            let _ = app.save();
+            eprintln!("Error: {}", err);
+        }
+        Err(payload) => {
+            // The game may be mid-update, so keep the regular save intact
+            match app.emergency_save() {
+                Ok(Some(label)) => eprintln!(
+                    "Solaris crashed. Progress was saved to '{}'; open it with --load {}",
+                    label, label
+                ),
+                Ok(None) => {}
+                Err(e) => eprintln!("Solaris crashed and the emergency save failed: {}", e),
+            }
+            panic::resume_unwind(payload);
+        }
+    }
+
+    restored
+}
+
+/// Enter raw mode and the alternate screen, and make sure a panic message
+/// is printed to the normal screen
+fn setup_terminal() -> io::Result<Terminal<CrosstermBackend<io::Stdout>>> {
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        let _ = restore_terminal();
+        default_hook(info);
+    }));
+    Terminal::new(CrosstermBackend::new(stdout))
+}
 
-    // Restore terminal
+/// Leave raw mode and the alternate screen. Also called from the panic hook,
+/// where the `Terminal` isn't reachable.
+fn restore_terminal() -> io::Result<()> {
     disable_raw_mode()?;
     execute!(
-        terminal.backend_mut(),
+        io::stdout(),
         LeaveAlternateScreen,
-        DisableMouseCapture
-    )?;
-    terminal.show_cursor()?;
+        DisableMouseCapture,
+        cursor::Show
+    )
+}
 
-    if let Err(err) = result {
-        eprintln!("Error: {}", err);
-    }
+/// Start the JSON-RPC API if a port was given, saying where it listens
+fn start_api(port: Option<u16>) -> io::Result<Option<ApiServer>> {
+    let Some(port) = port else {
+        return Ok(None);
+    };
+    let api = ApiServer::start(port)?;
+    eprintln!(
+        "API listening on {} (token in {})",
+        api.addr,
+        api::get_token_path()?.display()
+    );
+    Ok(Some(api))
+}
 
-    Ok(())
+/// A flag set when the terminal closes or the process is asked to stop, so the
+/// game loop can save and exit. A second signal exits immediately. Without
+/// `hangup`, closing the terminal is ignored instead.
+fn register_shutdown_signals(hangup: bool) -> io::Result<Arc<AtomicBool>> {
+    let shutdown = Arc::new(AtomicBool::new(false));
+    #[cfg(unix)]
+    {
+        use signal_hook::consts::{SIGHUP, SIGINT, SIGQUIT, SIGTERM};
+        for signal in [SIGINT, SIGTERM, SIGQUIT] {
+            signal_hook::flag::register_conditional_shutdown(signal, 1, Arc::clone(&shutdown))?;
+            signal_hook::flag::register(signal, Arc::clone(&shutdown))?;
+        }
+        if hangup {
+            signal_hook::flag::register_conditional_shutdown(SIGHUP, 1, Arc::clone(&shutdown))?;
+            signal_hook::flag::register(SIGHUP, Arc::clone(&shutdown))?;
+        } else {
+            signal_hook::flag::register(SIGHUP, Arc::new(AtomicBool::new(false)))?;
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = hangup;
+    Ok(shutdown)
 }
 
 fn print_help() {
     println!("Solaris - Terminal-based idle game");
     println!();
     println!("Usage: solaris [OPTIONS]");
+    println!("       solaris status [--label <label>] [--format plain|json|tmux]");
+    println!("       solaris daemon [--label <label>]   (see solaris daemon --help)");
+    println!("       solaris attach [--label <label>]   (see solaris attach --help)");
+    println!("       solaris replay <file> [--watch]    (see solaris replay --help)");
     println!();
     println!("Save Management:");
     println!("  --new <label>    Create a new save with the given label");
     println!("  --load <label>   Load a specific save");
-    println!("  --list           List all available saves");
+    println!("  --list           List all available saves without opening any");
+    println!("  --json           With --list, print the save summaries as JSON");
     println!("  --delete <label> Delete a specific save (with confirmation)");
+    println!("  --read-only      Open a save another instance is playing, without saving");
+    println!("  --take-over      Open a save another instance is playing; it stops saving");
     println!();
     println!("  Without options, loads the last used save or creates 'main' if none exist.");
     println!();
     println!("Game Options:");
     println!("  --auto           Enable auto-play mode (buys producers and upgrades)");
     println!("  --speed <N>      Set auto-play speed multiplier (default: 1, max effective: ~10)");
+    println!("  --theme <name>   Color theme: default, solarized, monochrome, high-contrast,");
+    println!("                   or a user theme in <data dir>/themes/<name>.json");
+    println!("                   (NO_COLOR disables colors regardless)");
+    println!("  --fps <N>        Redraw at most N times per second (default: 60); the game");
+    println!("                   changes 10 times a second, so higher only speeds up input");
+    println!("  --no-animation   Freeze the star field and orbits");
+    println!("  --autosave <N>   Autosave every N seconds (default: 30)");
+    println!("  --record <file>  Record inputs for 'solaris replay <file>'");
+    println!("  --api-port <N>   Serve the JSON-RPC control API on 127.0.0.1:N (default: off)");
+    println!("  --ascii          Draw icons and animations with plain ASCII characters");
+    println!("                   (the default when the locale isn't UTF-8)");
+    println!("  --unicode        Use Unicode glyphs even if the locale isn't UTF-8");
     println!("  --help           Show this help message");
 }
 
-fn handle_list() -> io::Result<()> {
+fn handle_list(json: bool) -> io::Result<()> {
     // Migrate legacy save first if needed
     if let Ok(Some(migrated_label)) = save::migrate_legacy_save() {
-        println!("Migrated existing save to '{}'", migrated_label);
-        println!();
+        if !json {
+            println!("Migrated existing save to '{}'", migrated_label);
+            println!();
+        }
     }
 
     let saves = save::list_saves()?;
     let last_used = save::get_last_used()?.unwrap_or_default();
+    let summaries: Vec<(String, io::Result<Option<save::SaveSummary>>)> = saves
+        .into_iter()
+        .map(|info| {
+            let summary = save::peek_save(&info.label);
+            (info.label, summary)
+        })
+        .collect();
+
+    if json {
+        let entries: Vec<serde_json::Value> = summaries
+            .iter()
+            .map(|(label, summary)| {
+                let mut entry = match summary {
+                    Ok(Some(summary)) => serde_json::to_value(summary)
+                        .unwrap_or_else(|_| serde_json::json!({ "label": label })),
+                    Ok(None) => serde_json::json!({ "label": label }),
+                    Err(e) => serde_json::json!({ "label": label, "error": e.to_string() }),
+                };
+                entry["last_used"] = serde_json::Value::Bool(*label == last_used);
+                entry
+            })
+            .collect();
+        let output = serde_json::to_string_pretty(&entries)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        println!("{}", output);
+        return Ok(());
+    }
 
-    if saves.is_empty() {
+    if summaries.is_empty() {
         println!("No saves found.");
         println!();
         println!("Create a new save with: solaris --new <label>");
         return Ok(());
     }
 
-    println!("Available saves:");
-    println!();
-
-    for save_info in &saves {
-        let marker = if save_info.label == last_used {
-            " *"
+    let label_width = summaries
+        .iter()
+        .map(|(label, _)| label.len() + 2)
+        .max()
+        .unwrap_or(0)
+        .max("Save".len());
+
+    println!(
+        "  {:<label_width$}  {:<16}  {:>24}  {:>26}  {:>4}  {:>8}  {:>10}",
+        "Save", "Last played", "Energy", "E/s", "Asc", "Chips", "Play time"
+    );
+    for (label, summary) in &summaries {
+        let name = if *label == last_used {
+            format!("{} *", label)
         } else {
-            ""
+            label.clone()
         };
 
-        // Try to read the save to get last save time
-        if let Ok(Some(save_data)) = save::load_game(&save_info.label) {
-            let time_ago = format_time_ago(save_data.last_save);
-            println!(
-                "  {}{} (last played: {})",
-                save_info.label, marker, time_ago
-            );
-        } else {
-            println!("  {}{}", save_info.label, marker);
+        match summary {
+            Ok(Some(s)) => println!(
+                "  {:<label_width$}  {:<16}  {:>24}  {:>26}  {:>4}  {:>8}  {:>10}",
+                name,
+                format::format_time_ago(s.last_save),
+                format::format_energy(s.energy),
+                format::format_rate(s.energy_per_second),
+                s.ascensions,
+                s.stellar_chips,
+                format::format_duration(s.play_time_secs)
+            ),
+            Ok(None) => println!("  {}", name),
+            Err(e) => println!("  {:<label_width$}  (unreadable: {})", name, e),
         }
     }
 
@@ -259,34 +654,6 @@ fn handle_list() -> io::Result<()> {
     Ok(())
 }
 
-fn format_time_ago(time: DateTime<Utc>) -> String {
-    let now = Utc::now();
-    let duration = now.signed_duration_since(time);
-
-    let secs = duration.num_seconds();
-    if secs < 60 {
-        return "just now".to_string();
-    }
-
-    let mins = duration.num_minutes();
-    if mins < 60 {
-        return format!("{} minute{} ago", mins, if mins == 1 { "" } else { "s" });
-    }
-
-    let hours = duration.num_hours();
-    if hours < 24 {
-        return format!("{} hour{} ago", hours, if hours == 1 { "" } else { "s" });
-    }
-
-    let days = duration.num_days();
-    if days < 30 {
-        return format!("{} day{} ago", days, if days == 1 { "" } else { "s" });
-    }
-
-    let months = days / 30;
-    format!("{} month{} ago", months, if months == 1 { "" } else { "s" })
-}
-
 fn handle_delete(label: Option<String>) -> io::Result<()> {
     // Migrate legacy save first if needed
     if let Ok(Some(migrated_label)) = save::migrate_legacy_save() {
@@ -343,6 +710,13 @@ fn handle_delete(label: Option<String>) -> io::Result<()> {
         println!("Save '{}' not found.", label);
         return Ok(());
     }
+    if let Some(owner) = lock::held_elsewhere(&label)? {
+        eprintln!(
+            "Error: Save '{}' is open in another instance ({}). Quit it first.",
+            label, owner
+        );
+        return Ok(());
+    }
 
     println!("This will permanently delete the save '{}':", label);
     println!("  {}", save_path.display());
@@ -365,51 +739,115 @@ fn handle_delete(label: Option<String>) -> io::Result<()> {
     Ok(())
 }
 
+/// Loop options that come from the command line and settings
+struct RunSettings<'a> {
+    fps: u32,
+    autosave_interval: Duration,
+    /// Set by a signal; the loop saves and returns
+    shutdown: &'a AtomicBool,
+    /// Requests from the control API, run between ticks
+    api: Option<&'a ApiServer>,
+}
+
 fn run_app<B: Backend>(
     terminal: &mut Terminal<B>,
     app: &mut App,
     auto_player: &mut Option<AutoPlayer>,
+    recorder: &mut Option<Recorder>,
+    settings: &RunSettings,
 ) -> io::Result<()> {
+    let tick_interval = Duration::from_millis(TICK_RATE_MS);
+    let frame_interval = Duration::from_secs(1) / settings.fps;
     let mut last_tick = Instant::now();
     let mut last_save = Instant::now();
+    let mut last_draw: Option<Instant> = None;
 
     loop {
-        // Render every frame (~60 FPS)
-        terminal.draw(|f| ui::render(f, app))?;
+        if settings.shutdown.load(Ordering::Relaxed) {
+            let _ = app.save();
+            return Ok(());
+        }
+
+        // Only draw when something changed, and no more often than the FPS cap
+        if app.needs_redraw && last_draw.is_none_or(|t| t.elapsed() >= frame_interval) {
+            terminal.draw(|f| ui::render(f, app))?;
+            app.needs_redraw = false;
+            last_draw = Some(Instant::now());
+            if let Some(recorder) = recorder {
+                recorder.layout(app);
+            }
+        }
 
-        // Handle events with short timeout for responsiveness
-        let timeout = Duration::from_millis(FRAME_RATE_MS);
+        // Sleep until the next tick, or until the next frame may be drawn if one is waiting
+        let mut timeout = tick_interval.saturating_sub(last_tick.elapsed());
+        if let Some(drawn) = last_draw.filter(|_| app.needs_redraw) {
+            timeout = timeout.min(frame_interval.saturating_sub(drawn.elapsed()));
+        }
 
         if event::poll(timeout)? {
-            if let event::Event::Key(key) = event::read()? {
-                if input::handle_key(app, key) {
-                    // Save on quit
-                    let _ = app.save();
-                    return Ok(());
-                }
-                // Pause auto-player on any user input
-                if let Some(ref mut player) = auto_player {
-                    player.pause();
+            match event::read()? {
+                event::Event::Key(key) => {
+                    app.needs_redraw = true;
+                    if let Some(recorder) = recorder {
+                        recorder.key(key);
+                    }
+                    if input::handle_key(app, key) {
+                        // Save on quit
+                        let _ = app.save();
+                        return Ok(());
+                    }
+                    // Pause auto-player on any user input
+                    if let Some(ref mut player) = auto_player {
+                        player.pause();
+                    }
+                    // Other slots aren't part of the recording, so it ends
+                    // before the save manager can switch to or change one
+                    if app.show_saves {
+                        if let Some(recorder) = recorder.take() {
+                            app.save_status = Some(match recorder.finish(&app.game) {
+                                Ok(()) => "Recording stopped at the save manager".to_string(),
+                                Err(e) => format!("Recording stopped: {}", e),
+                            });
+                        }
+                    }
                 }
+                event::Event::Resize(..) => app.needs_redraw = true,
+                _ => {}
             }
         }
 
+        if let Some(api) = settings.api {
+            api.handle_pending(app, |method, params| {
+                if let Some(recorder) = recorder {
+                    recorder.api(method, params);
+                }
+            });
+        }
+
         // Game tick at 10 Hz
-        if last_tick.elapsed() >= Duration::from_millis(TICK_RATE_MS) {
+        if last_tick.elapsed() >= tick_interval {
             app.tick();
 
             // Auto-player tick (runs after game tick so it sees fresh state)
             if let Some(ref mut player) = auto_player {
                 player.tick(app);
-                app.auto_paused = player.is_paused();
+                // The auto-player moves the selection as it plays
+                let paused = player.is_paused();
+                if !paused || paused != app.auto_paused {
+                    app.needs_redraw = true;
+                }
+                app.auto_paused = paused;
+            }
+            if let Some(recorder) = recorder {
+                recorder.tick();
             }
 
             last_tick = Instant::now();
         }
 
-        // Auto-save every 30 seconds
-        if last_save.elapsed() >= Duration::from_secs(AUTOSAVE_INTERVAL_SECS) {
-            let _ = app.save();
+        // Auto-save every 30 seconds unless configured otherwise
+        if last_save.elapsed() >= settings.autosave_interval {
+            app.autosave();
             last_save = Instant::now();
         }
     }
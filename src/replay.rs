@@ -0,0 +1,507 @@
+//! Input recordings (`solaris --record <file>`) and `solaris replay <file>`.
+//!
+//! A recording is one JSON entry per line: the save as it was on disk when
+//! the game started, then every input with the number of ticks that had run
+//! when it arrived, then the final game. Time comes from a `TickClock` on
+//! both sides, so replaying the inputs at the same ticks gives the same game.
+
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use crossterm::event::{self, KeyCode, KeyEvent};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::app::{App, LayoutMode, SaveData};
+use crate::auto::AutoPlayer;
+use crate::clock::{self, TickClock};
+use crate::format::{format_energy, format_rate};
+use crate::game::GameState;
+use crate::{api, input, ui};
+
+#[derive(Serialize, Deserialize)]
+enum Entry {
+    Start {
+        label: String,
+        started: DateTime<Utc>,
+        /// `None` for a save created by this session
+        save: Option<SaveData>,
+        /// Set when playing with `--auto`, whose choices follow from the ticks
+        auto_speed: Option<f64>,
+    },
+    Action {
+        tick: u64,
+        action: Action,
+    },
+    End {
+        ticks: u64,
+        game: GameState,
+    },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+enum Action {
+    Key(KeyEvent),
+    /// What drawing at the terminal's size decided, since keys act on the layout
+    Layout {
+        mode: LayoutMode,
+        two_column_stats: bool,
+    },
+    /// A control API call that may change the game
+    Api {
+        method: String,
+        params: Value,
+    },
+}
+
+/// Writes a recording as the game runs. Each entry is flushed straight away
+/// so a crash still leaves the inputs that led to it.
+pub struct Recorder {
+    writer: BufWriter<File>,
+    ticks: u64,
+    layout: Option<(LayoutMode, bool)>,
+    /// The first write that failed; the game carries on and reports it at the end
+    error: Option<io::Error>,
+}
+
+impl Recorder {
+    /// Start recording a game that is about to load `save`
+    pub fn create(
+        path: &Path,
+        label: &str,
+        started: DateTime<Utc>,
+        save: Option<SaveData>,
+        auto_speed: Option<f64>,
+    ) -> io::Result<Self> {
+        let mut recorder = Self {
+            writer: BufWriter::new(File::create(path)?),
+            ticks: 0,
+            layout: None,
+            error: None,
+        };
+        recorder.write(&Entry::Start {
+            label: label.to_string(),
+            started,
+            save,
+            auto_speed,
+        });
+        match recorder.error.take() {
+            Some(e) => Err(e),
+            None => Ok(recorder),
+        }
+    }
+
+    pub fn tick(&mut self) {
+        self.ticks += 1;
+    }
+
+    pub fn key(&mut self, key: KeyEvent) {
+        self.action(Action::Key(key));
+    }
+
+    /// Call after drawing; only changes are written
+    pub fn layout(&mut self, app: &App) {
+        let layout = (app.layout_mode, app.two_column_stats);
+        if self.layout != Some(layout) {
+            self.layout = Some(layout);
+            self.action(Action::Layout {
+                mode: layout.0,
+                two_column_stats: layout.1,
+            });
+        }
+    }
+
+    pub fn api(&mut self, method: &str, params: &Value) {
+        self.action(Action::Api {
+            method: method.to_string(),
+            params: params.clone(),
+        });
+    }
+
+    /// Write the final game, which a replay checks itself against
+    pub fn finish(mut self, game: &GameState) -> io::Result<()> {
+        self.write(&Entry::End {
+            ticks: self.ticks,
+            game: game.clone(),
+        });
+        match self.error {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
+    }
+
+    fn action(&mut self, action: Action) {
+        self.write(&Entry::Action {
+            tick: self.ticks,
+            action,
+        });
+    }
+
+    fn write(&mut self, entry: &Entry) {
+        if self.error.is_some() {
+            return;
+        }
+        let result = serde_json::to_writer(&mut self.writer, entry)
+            .map_err(io::Error::from)
+            .and_then(|()| self.writer.write_all(b"\n"))
+            .and_then(|()| self.writer.flush());
+        if let Err(e) = result {
+            self.error = Some(e);
+        }
+    }
+}
+
+struct Recording {
+    label: String,
+    started: DateTime<Utc>,
+    save: Option<SaveData>,
+    auto_speed: Option<f64>,
+    actions: Vec<(u64, Action)>,
+    /// Missing if the recorded game crashed or was killed
+    end: Option<(u64, GameState)>,
+}
+
+fn read_recording(path: &Path) -> io::Result<Recording> {
+    let invalid = |message: String| io::Error::new(io::ErrorKind::InvalidData, message);
+    let text = fs::read_to_string(path)?;
+    let mut entries = text.lines().enumerate().map(|(i, line)| {
+        serde_json::from_str::<Entry>(line).map_err(|e| invalid(format!("line {}: {}", i + 1, e)))
+    });
+
+    let Some(Entry::Start {
+        label,
+        started,
+        save,
+        auto_speed,
+    }) = entries.next().transpose()?
+    else {
+        return Err(invalid(
+            "not a recording: it doesn't start with the save".to_string(),
+        ));
+    };
+    let mut recording = Recording {
+        label,
+        started,
+        save,
+        auto_speed,
+        actions: Vec::new(),
+        end: None,
+    };
+    for entry in entries {
+        match entry? {
+            Entry::Action { tick, action } => recording.actions.push((tick, action)),
+            Entry::End { ticks, game } => recording.end = Some((ticks, game)),
+            Entry::Start { .. } => return Err(invalid("more than one start entry".to_string())),
+        }
+    }
+    Ok(recording)
+}
+
+/// The recorded game at its starting point
+struct Replay {
+    app: App,
+    auto_player: Option<AutoPlayer>,
+    /// Layout the recorded terminal was using, which watching mustn't change
+    layout: (LayoutMode, bool),
+}
+
+impl Replay {
+    fn new(recording: &Recording) -> Self {
+        clock::set(TickClock::new(recording.started));
+        let mut app = App::new(recording.label.clone());
+        // Never write to the real save
+        app.read_only = true;
+        app.reduced_motion = true;
+        if let Some(speed) = recording.auto_speed {
+            app.auto_mode = true;
+            app.auto_speed = speed;
+        }
+        if let Some(save) = recording.save.clone() {
+            app.restore(save);
+        }
+        let layout = (app.layout_mode, app.two_column_stats);
+        Self {
+            app,
+            auto_player: recording.auto_speed.map(AutoPlayer::new),
+            layout,
+        }
+    }
+
+    fn apply(&mut self, action: &Action) {
+        let app = &mut self.app;
+        match action {
+            Action::Key(key) => {
+                input::handle_key(app, *key);
+                if let Some(player) = &mut self.auto_player {
+                    player.pause();
+                }
+            }
+            Action::Layout {
+                mode,
+                two_column_stats,
+            } => {
+                self.layout = (*mode, *two_column_stats);
+                app.layout_mode = *mode;
+                app.two_column_stats = *two_column_stats;
+            }
+            Action::Api { method, params } => {
+                let _ = api::dispatch(app, method, params.clone());
+            }
+        }
+    }
+
+    /// The same steps as `run_app` after its inputs
+    fn tick(&mut self) {
+        self.app.tick();
+        if let Some(player) = &mut self.auto_player {
+            player.tick(&mut self.app);
+            self.app.auto_paused = player.is_paused();
+        }
+    }
+}
+
+/// `solaris replay <file> [--watch] [--speed N]`
+pub fn run(args: &[String]) -> io::Result<()> {
+    let mut path: Option<&str> = None;
+    let mut watch = false;
+    let mut speed = 1.0;
+
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--watch" => {
+                watch = true;
+            }
+            "--speed" => {
+                i += 1;
+                match args.get(i).and_then(|v| v.parse::<f64>().ok()) {
+                    Some(v) if v > 0.0 => speed = v,
+                    _ => {
+                        eprintln!(
+                            "Error: --speed value must be a positive number (e.g. --speed 4)"
+                        );
+                        return Ok(());
+                    }
+                }
+            }
+            "--help" | "-h" => {
+                print_help();
+                return Ok(());
+            }
+            other if other.starts_with('-') => {
+                eprintln!("Unknown replay option: {}", other);
+                eprintln!("Use 'solaris replay --help' for usage information");
+                return Ok(());
+            }
+            other => path = Some(other),
+        }
+        i += 1;
+    }
+    let Some(path) = path else {
+        eprintln!("Error: replay requires a recording (e.g. solaris replay session.jsonl)");
+        return Ok(());
+    };
+
+    let recording = match read_recording(Path::new(path)) {
+        Ok(recording) => recording,
+        Err(e) => {
+            eprintln!("Error: Could not read recording '{}': {}", path, e);
+            return Ok(());
+        }
+    };
+    let mut replay = Replay::new(&recording);
+    let ticks = recording
+        .end
+        .as_ref()
+        .map(|(ticks, _)| *ticks)
+        .or_else(|| recording.actions.last().map(|(tick, _)| *tick))
+        .unwrap_or(0);
+
+    let finished = if watch {
+        let mut terminal = crate::setup_terminal()?;
+        let result = watch_replay(&mut terminal, &mut replay, &recording.actions, ticks, speed);
+        crate::restore_terminal()?;
+        result?
+    } else {
+        replay_headless(&mut replay, &recording.actions, ticks);
+        true
+    };
+
+    let game = &replay.app.game;
+    if !finished {
+        println!("Stopped watching before the end of the recording");
+        return Ok(());
+    }
+    println!(
+        "Replayed {} inputs over {} ticks of '{}'",
+        recording.actions.len(),
+        ticks,
+        recording.label
+    );
+    println!(
+        "  Energy: {}  Rate: {}",
+        format_energy(game.energy),
+        format_rate(game.total_energy_per_second())
+    );
+
+    let Some((_, recorded)) = &recording.end else {
+        println!("The recording has no final state (the game didn't exit normally), so nothing to compare");
+        return Ok(());
+    };
+    let differences = differing_fields(recorded, game)?;
+    if differences.is_empty() {
+        println!("Final state matches the recording");
+    } else {
+        println!(
+            "Final state differs from the recording in: {}",
+            differences.join(", ")
+        );
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+fn print_help() {
+    println!("Usage: solaris replay <file> [--watch] [--speed <N>]");
+    println!();
+    println!("Re-run a game recorded with 'solaris --record <file>' and check that it");
+    println!("ends in the same state. Saves are never touched.");
+    println!();
+    println!("  --watch          Show the replay instead of running it all at once");
+    println!("  --speed <N>      With --watch, play N times faster (default: 1)");
+}
+
+fn replay_headless(replay: &mut Replay, actions: &[(u64, Action)], ticks: u64) {
+    let mut actions = actions.iter().peekable();
+    for tick in 0..=ticks {
+        while let Some((_, action)) = actions.next_if(|(t, _)| *t == tick) {
+            replay.apply(action);
+        }
+        if tick < ticks {
+            replay.tick();
+        }
+    }
+}
+
+/// Replay at `speed` times the game's pace, drawing every tick. Returns
+/// false if the viewer quit early.
+fn watch_replay<B: ratatui::backend::Backend>(
+    terminal: &mut ratatui::Terminal<B>,
+    replay: &mut Replay,
+    actions: &[(u64, Action)],
+    ticks: u64,
+    speed: f64,
+) -> io::Result<bool> {
+    let tick_interval = Duration::from_millis(crate::TICK_RATE_MS).div_f64(speed);
+    let mut actions = actions.iter().peekable();
+    let mut next_tick = Instant::now();
+
+    for tick in 0..=ticks {
+        while let Some((_, action)) = actions.next_if(|(t, _)| *t == tick) {
+            replay.apply(action);
+        }
+
+        // Drawing fits the layout to this terminal; put the recorded one back
+        terminal.draw(|f| ui::render(f, &mut replay.app))?;
+        (replay.app.layout_mode, replay.app.two_column_stats) = replay.layout;
+
+        next_tick += tick_interval;
+        while let Some(wait) = next_tick.checked_duration_since(Instant::now()) {
+            if event::poll(wait)? {
+                if let event::Event::Key(key) = event::read()? {
+                    if matches!(key.code, KeyCode::Char('q') | KeyCode::Esc) {
+                        return Ok(false);
+                    }
+                }
+            }
+        }
+        if tick < ticks {
+            replay.tick();
+        }
+    }
+    Ok(true)
+}
+
+/// Top-level `GameState` fields whose saved form differs
+fn differing_fields(expected: &GameState, actual: &GameState) -> io::Result<Vec<String>> {
+    let to_value = |game: &GameState| {
+        serde_json::to_value(game).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    };
+    let (Value::Object(expected), Value::Object(actual)) = (to_value(expected)?, to_value(actual)?)
+    else {
+        return Ok(vec!["game".to_string()]);
+    };
+    Ok(expected
+        .iter()
+        .filter(|(field, value)| actual.get(*field) != Some(value))
+        .map(|(field, _)| field.clone())
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    #[test]
+    fn test_recording_replays_to_the_same_game() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("session.jsonl");
+        let started = Utc::now();
+
+        // Play a short game the way run_app would, recording it
+        clock::set(TickClock::new(started));
+        let mut save = GameState::new();
+        save.energy = 40.0;
+        let save = SaveData {
+            game_state: save,
+            last_save: started - chrono::TimeDelta::minutes(10),
+        };
+        let mut recorder =
+            Recorder::create(&path, "main", started, Some(save.clone()), None).unwrap();
+        let mut app = App::new("main".to_string());
+        app.read_only = true;
+        app.restore(save);
+        recorder.layout(&app);
+
+        let space = KeyEvent::new(KeyCode::Char(' '), KeyModifiers::NONE);
+        let enter = KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE);
+        for tick in 0..50 {
+            if tick % 7 == 0 {
+                recorder.key(space);
+                input::handle_key(&mut app, space);
+            }
+            if tick == 20 {
+                recorder.key(enter);
+                input::handle_key(&mut app, enter);
+            }
+            app.tick();
+            recorder.tick();
+        }
+        let params = serde_json::json!({ "id": 1 });
+        recorder.api("buy_producer", &params);
+        let _ = api::dispatch(&mut app, "buy_producer", params);
+        recorder.finish(&app.game).unwrap();
+
+        let recording = read_recording(&path).unwrap();
+        assert_eq!(recording.actions.len(), 11);
+        let mut replay = Replay::new(&recording);
+        replay_headless(&mut replay, &recording.actions, 50);
+        let (ticks, recorded) = recording.end.unwrap();
+        assert_eq!(ticks, 50);
+        assert!(differing_fields(&recorded, &replay.app.game)
+            .unwrap()
+            .is_empty());
+        assert!(replay.app.game.producer_count(1) > 0);
+
+        // A different game is caught
+        replay.app.game.energy += 1.0;
+        assert_eq!(
+            differing_fields(&recorded, &replay.app.game).unwrap(),
+            vec!["energy".to_string()]
+        );
+    }
+}
@@ -1,16 +1,51 @@
-use std::fs;
+use std::collections::BTreeMap;
+use std::fs::{self, OpenOptions};
 use std::io;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
 
+use chrono::{DateTime, Utc};
 use directories::ProjectDirs;
 use serde::{Deserialize, Serialize};
 
 use crate::app::SaveData;
+use crate::lock;
 
-/// Metadata tracking which save was last used
+/// Metadata tracking which save was last used, plus a summary of every slot
+/// so listing saves doesn't have to load them
 #[derive(Serialize, Deserialize, Default)]
 pub struct SaveMeta {
     pub last_used: Option<String>,
+    #[serde(default)]
+    pub summaries: BTreeMap<String, SaveSummary>,
+}
+
+/// At-a-glance numbers for a save slot, written alongside every save
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SaveSummary {
+    pub label: String,
+    pub last_save: DateTime<Utc>,
+    pub energy: f64,
+    pub energy_per_second: f64,
+    pub ascensions: u64,
+    pub stellar_chips: u64,
+    /// Time played in the current ascension
+    pub play_time_secs: u64,
+}
+
+impl SaveSummary {
+    pub fn from_save_data(label: &str, save_data: &SaveData) -> Self {
+        let game = &save_data.game_state;
+        Self {
+            label: label.to_string(),
+            last_save: save_data.last_save,
+            energy: game.energy,
+            energy_per_second: game.total_energy_per_second(),
+            ascensions: game.total_ascensions,
+            stellar_chips: game.stellar_chips,
+            play_time_secs: game.time_played_seconds(),
+        }
+    }
 }
 
 /// Information about a save file
@@ -20,7 +55,7 @@ pub struct SaveInfo {
 }
 
 /// Get the base data directory for solaris
-fn get_data_dir() -> io::Result<PathBuf> {
+pub fn get_data_dir() -> io::Result<PathBuf> {
     let proj_dirs = ProjectDirs::from("", "", "solaris")
         .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "Could not find data directory"))?;
 
@@ -84,6 +119,26 @@ pub fn get_save_path(label: &str) -> io::Result<PathBuf> {
     Ok(get_saves_dir()?.join(format!("{}.json", sanitized)))
 }
 
+/// Get the path to a save slot's lock file
+pub fn get_lock_path(label: &str) -> io::Result<PathBuf> {
+    let sanitized = sanitize_label(label);
+    Ok(get_saves_dir()?.join(format!("{}.lock", sanitized)))
+}
+
+/// Get the path to the socket of the daemon running a save slot
+pub fn get_socket_path(label: &str) -> io::Result<PathBuf> {
+    let sanitized = sanitize_label(label);
+    Ok(get_saves_dir()?.join(format!("{}.sock", sanitized)))
+}
+
+/// Fail if another running instance has the slot open
+fn ensure_not_open_elsewhere(label: &str) -> io::Result<()> {
+    match lock::held_elsewhere(label)? {
+        Some(owner) => Err(lock::held_error(label, &owner)),
+        None => Ok(()),
+    }
+}
+
 /// Load the save metadata
 pub fn load_meta() -> io::Result<SaveMeta> {
     let path = get_meta_path()?;
@@ -95,21 +150,55 @@ pub fn load_meta() -> io::Result<SaveMeta> {
     serde_json::from_str(&json).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
 }
 
-/// Save the metadata
-pub fn save_meta(meta: &SaveMeta) -> io::Result<()> {
+/// Save the metadata; changes go through `update_meta`
+fn save_meta(meta: &SaveMeta) -> io::Result<()> {
     let path = get_meta_path()?;
     let json = serde_json::to_string_pretty(meta)
         .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-    fs::write(path, json)
+    write_atomic(&path, json.as_bytes())
 }
 
-/// Update the last used save label
-pub fn set_last_used(label: &str) -> io::Result<()> {
+/// Load, change and save the metadata while holding `meta.lock`, so
+/// instances saving different slots don't drop each other's summaries
+pub fn update_meta(update: impl FnOnce(&mut SaveMeta)) -> io::Result<()> {
+    let lock_file = OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(get_data_dir()?.join("meta.lock"))?;
+    // Released when the file closes, or by the OS if the process dies
+    lock_file.lock()?;
     let mut meta = load_meta()?;
-    meta.last_used = Some(label.to_string());
+    update(&mut meta);
     save_meta(&meta)
 }
 
+/// Replace a file's contents so that readers see either the old file or
+/// the new one, never a partly written one
+pub fn write_atomic(path: &Path, contents: &[u8]) -> io::Result<()> {
+    let temp = temp_path(path);
+    let result = fs::write(&temp, contents).and_then(|()| fs::rename(&temp, path));
+    if result.is_err() {
+        let _ = fs::remove_file(&temp);
+    }
+    result
+}
+
+/// A name next to `path`, unique to this write, for a file that will be
+/// moved into place once complete
+pub fn temp_path(path: &Path) -> PathBuf {
+    static NEXT: AtomicU64 = AtomicU64::new(0);
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    let n = NEXT.fetch_add(1, Ordering::Relaxed);
+    name.push(format!(".{}-{}.tmp", std::process::id(), n));
+    path.with_file_name(name)
+}
+
+/// Update the last used save label
+pub fn set_last_used(label: &str) -> io::Result<()> {
+    update_meta(|meta| meta.last_used = Some(label.to_string()))
+}
+
 /// Get the last used save label
 pub fn get_last_used() -> io::Result<Option<String>> {
     let meta = load_meta()?;
@@ -126,7 +215,7 @@ pub fn list_saves() -> io::Result<Vec<SaveInfo>> {
             let entry = entry?;
             let path = entry.path();
 
-            if path.extension().map_or(false, |ext| ext == "json") {
+            if path.extension().is_some_and(|ext| ext == "json") {
                 if let Some(stem) = path.file_stem() {
                     if let Some(label) = stem.to_str() {
                         saves.push(SaveInfo {
@@ -155,32 +244,74 @@ pub fn save_game(label: &str, save_data: &SaveData) -> io::Result<()> {
     let path = get_save_path(label)?;
     let json = serde_json::to_string_pretty(save_data)
         .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-    fs::write(path, json)?;
-
-    // Update last used
-    set_last_used(label)?;
+    // `solaris status` may read the slot while it's being saved
+    write_atomic(&path, json.as_bytes())?;
+
+    // Update last used and the slot's summary in one write
+    let label = sanitize_label(label);
+    update_meta(|meta| {
+        meta.summaries.insert(
+            label.clone(),
+            SaveSummary::from_save_data(&label, save_data),
+        );
+        meta.last_used = Some(label);
+    })
+}
 
-    Ok(())
+/// Write a crashed game to `<label>-emergency`, a slot of its own next to
+/// the regular one, which is left as it was. Returns the new label.
+pub fn write_emergency_save(label: &str, save_data: &SaveData) -> io::Result<String> {
+    let label = format!("{}-emergency", sanitize_label(label));
+    let json = serde_json::to_string_pretty(save_data)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    fs::write(get_save_path(&label)?, json)?;
+    Ok(label)
 }
 
-/// Load the game from a specific save slot
-pub fn load_game(label: &str) -> io::Result<Option<SaveData>> {
+/// Read a save slot's summary without loading it into the game or marking it
+/// as last used. Slots saved before summaries existed are read in full each
+/// time, until the game next saves them.
+pub fn peek_save(label: &str) -> io::Result<Option<SaveSummary>> {
     let path = get_save_path(label)?;
-
     if !path.exists() {
         return Ok(None);
     }
 
+    let meta = load_meta()?;
+    if let Some(summary) = meta.summaries.get(&sanitize_label(label)) {
+        return Ok(Some(summary.clone()));
+    }
+
     let json = fs::read_to_string(path)?;
     let save_data: SaveData =
         serde_json::from_str(&json).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    Ok(Some(SaveSummary::from_save_data(label, &save_data)))
+}
 
-    // Update last used
-    set_last_used(label)?;
+/// Read a save slot without marking it as last used
+pub fn read_save(label: &str) -> io::Result<Option<SaveData>> {
+    let path = get_save_path(label)?;
+
+    if !path.exists() {
+        return Ok(None);
+    }
 
+    let json = fs::read_to_string(path)?;
+    let save_data: SaveData =
+        serde_json::from_str(&json).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
     Ok(Some(save_data))
 }
 
+/// Load the game from a specific save slot
+pub fn load_game(label: &str) -> io::Result<Option<SaveData>> {
+    let save_data = read_save(label)?;
+    if save_data.is_some() {
+        // Update last used
+        set_last_used(label)?;
+    }
+    Ok(save_data)
+}
+
 /// Delete a save
 pub fn delete_save(label: &str) -> io::Result<bool> {
     let path = get_save_path(label)?;
@@ -188,20 +319,95 @@ pub fn delete_save(label: &str) -> io::Result<bool> {
     if !path.exists() {
         return Ok(false);
     }
+    ensure_not_open_elsewhere(label)?;
 
     fs::remove_file(path)?;
 
-    // If this was the last used save, clear it from meta
-    let meta = load_meta()?;
-    if meta.last_used.as_deref() == Some(label) {
-        let mut new_meta = meta;
-        new_meta.last_used = None;
-        save_meta(&new_meta)?;
-    }
+    // Forget its summary, and clear it as last used if it was
+    let label = sanitize_label(label);
+    update_meta(|meta| {
+        meta.summaries.remove(&label);
+        if meta.last_used.as_deref() == Some(label.as_str()) {
+            meta.last_used = None;
+        }
+    })?;
 
     Ok(true)
 }
 
+/// Copy a save slot to a new label, e.g. to branch before an ascension.
+/// Fails if the target already exists; `last_used` is left alone.
+pub fn duplicate_save(from: &str, to: &str) -> io::Result<()> {
+    let from_path = get_save_path(from)?;
+    let to_path = get_save_path(to)?;
+    if !from_path.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("Save '{}' not found", from),
+        ));
+    }
+    if to_path.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("Save '{}' already exists", to),
+        ));
+    }
+
+    fs::copy(&from_path, &to_path)?;
+
+    update_meta(|meta| {
+        if let Some(summary) = meta.summaries.get(&sanitize_label(from)).cloned() {
+            let to = sanitize_label(to);
+            meta.summaries.insert(
+                to.clone(),
+                SaveSummary {
+                    label: to,
+                    ..summary
+                },
+            );
+        }
+    })
+}
+
+/// Rename a save slot, carrying its summary and `last_used` over to the new label.
+/// Fails if the target already exists or another instance has the slot open.
+pub fn rename_save(from: &str, to: &str) -> io::Result<()> {
+    let from_path = get_save_path(from)?;
+    let to_path = get_save_path(to)?;
+    if !from_path.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("Save '{}' not found", from),
+        ));
+    }
+    if to_path.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("Save '{}' already exists", to),
+        ));
+    }
+    ensure_not_open_elsewhere(from)?;
+
+    fs::rename(&from_path, &to_path)?;
+
+    let from = sanitize_label(from);
+    let to = sanitize_label(to);
+    update_meta(|meta| {
+        if let Some(summary) = meta.summaries.remove(&from) {
+            meta.summaries.insert(
+                to.clone(),
+                SaveSummary {
+                    label: to.clone(),
+                    ..summary
+                },
+            );
+        }
+        if meta.last_used.as_deref() == Some(from.as_str()) {
+            meta.last_used = Some(to);
+        }
+    })
+}
+
 /// Migrate legacy save.json to the new saves system
 /// Returns the label of the migrated save if migration occurred
 pub fn migrate_legacy_save() -> io::Result<Option<String>> {
@@ -252,3 +458,55 @@ pub fn resolve_save_label(explicit: Option<&str>) -> io::Result<Option<String>>
     // No saves exist
     Ok(None)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::GameState;
+
+    #[test]
+    fn test_summary_from_save_data() {
+        let mut game = GameState::new();
+        game.energy = 500.0;
+        game.producers_owned.insert(1, 10);
+        game.ticks_played = 1200;
+        game.total_ascensions = 3;
+        game.stellar_chips = 42;
+        let save_data = SaveData {
+            game_state: game,
+            last_save: Utc::now(),
+        };
+
+        let summary = SaveSummary::from_save_data("main", &save_data);
+        assert_eq!(summary.label, "main");
+        assert_eq!(summary.energy, 500.0);
+        assert_eq!(
+            summary.energy_per_second,
+            save_data.game_state.total_energy_per_second()
+        );
+        assert_eq!(summary.ascensions, 3);
+        assert_eq!(summary.stellar_chips, 42);
+        assert_eq!(summary.play_time_secs, 120);
+    }
+
+    #[test]
+    fn test_write_atomic_replaces_in_place() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("meta.json");
+
+        write_atomic(&path, b"first").unwrap();
+        write_atomic(&path, b"second").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "second");
+        assert_ne!(temp_path(&path), temp_path(&path));
+
+        // Nothing but the file itself is left behind
+        assert_eq!(fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn test_meta_without_summaries_still_loads() {
+        let meta: SaveMeta = serde_json::from_str(r#"{"last_used": "main"}"#).unwrap();
+        assert_eq!(meta.last_used.as_deref(), Some("main"));
+        assert!(meta.summaries.is_empty());
+    }
+}
@@ -0,0 +1,119 @@
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::save::get_data_dir;
+
+/// User preferences shared by every save slot, stored in `settings.json`
+/// next to the saves directory. Missing fields fall back to their defaults.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub layout: LayoutSettings,
+    /// Theme preset or user theme name; the default theme when unset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub theme: Option<String>,
+    /// Seconds between autosaves; 30 when unset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub autosave_secs: Option<u64>,
+    /// Port for the local JSON-RPC API; the API is off when unset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub api_port: Option<u16>,
+}
+
+/// Split percentages for the multi-column layouts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LayoutSettings {
+    /// Width of the Producers column, as a share of the columns left of Stats
+    pub left_percent: u16,
+    /// Height of the Visualization (or Stats) panel above Upgrades
+    pub right_top_percent: u16,
+    /// Width of the Stats column in the three-column layout
+    pub stats_percent: u16,
+}
+
+impl Default for LayoutSettings {
+    fn default() -> Self {
+        Self {
+            left_percent: 50,
+            right_top_percent: 50,
+            stats_percent: 25,
+        }
+    }
+}
+
+impl LayoutSettings {
+    pub const MIN_PERCENT: u16 = 20;
+    pub const MAX_PERCENT: u16 = 80;
+    pub const MAX_STATS_PERCENT: u16 = 40;
+
+    /// Keep every split within a range where no panel collapses
+    pub fn clamped(self) -> Self {
+        Self {
+            left_percent: self
+                .left_percent
+                .clamp(Self::MIN_PERCENT, Self::MAX_PERCENT),
+            right_top_percent: self
+                .right_top_percent
+                .clamp(Self::MIN_PERCENT, Self::MAX_PERCENT),
+            stats_percent: self
+                .stats_percent
+                .clamp(Self::MIN_PERCENT, Self::MAX_STATS_PERCENT),
+        }
+    }
+}
+
+fn get_settings_path() -> io::Result<PathBuf> {
+    Ok(get_data_dir()?.join("settings.json"))
+}
+
+/// Load settings, falling back to defaults if the file is missing
+pub fn load_settings() -> io::Result<Settings> {
+    let path = get_settings_path()?;
+    if !path.exists() {
+        return Ok(Settings::default());
+    }
+
+    let json = fs::read_to_string(&path)?;
+    let mut settings: Settings =
+        serde_json::from_str(&json).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    settings.layout = settings.layout.clamped();
+    Ok(settings)
+}
+
+pub fn save_settings(settings: &Settings) -> io::Result<()> {
+    let path = get_settings_path()?;
+    let json = serde_json::to_string_pretty(settings)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    fs::write(path, json)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partial_settings_use_defaults() {
+        let settings: Settings =
+            serde_json::from_str(r#"{"layout": {"left_percent": 35}}"#).unwrap();
+        assert_eq!(settings.layout.left_percent, 35);
+        assert_eq!(settings.layout.right_top_percent, 50);
+        assert_eq!(settings.layout.stats_percent, 25);
+    }
+
+    #[test]
+    fn test_layout_clamped() {
+        let layout = LayoutSettings {
+            left_percent: 5,
+            right_top_percent: 95,
+            stats_percent: 60,
+        }
+        .clamped();
+        assert_eq!(layout.left_percent, LayoutSettings::MIN_PERCENT);
+        assert_eq!(layout.right_top_percent, LayoutSettings::MAX_PERCENT);
+        assert_eq!(layout.stats_percent, LayoutSettings::MAX_STATS_PERCENT);
+    }
+}
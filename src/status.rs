@@ -0,0 +1,273 @@
+use std::io;
+
+use chrono::{DateTime, Utc};
+
+use crate::app::App;
+use crate::format::{
+    format_cost, format_duration, format_energy_short, format_rate, format_time_ago,
+};
+use crate::game::{offline_progress, OfflineReport};
+use crate::glyphs::glyphs;
+use crate::hint::{next_purchase, NextPurchase};
+use crate::{lock, save};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Plain,
+    Json,
+    /// One line with tmux style codes, for `status-right`
+    Tmux,
+}
+
+/// Where a save stands right now, worked out without touching it
+struct Status {
+    label: String,
+    /// Another instance has the slot open, so it's progressing live
+    running: bool,
+    last_save: DateTime<Utc>,
+    /// Accrued since the last save, as `App::load` would credit it
+    offline: Option<OfflineReport>,
+    energy: f64,
+    energy_per_second: f64,
+    stellar_chips: u64,
+    /// Chips an ascension would earn now
+    chips_available: u64,
+    next: Option<NextPurchase>,
+}
+
+/// `solaris status [--label X] [--format plain|json|tmux]`
+pub fn run(args: &[String]) -> io::Result<()> {
+    let mut label: Option<String> = None;
+    let mut format = Format::Plain;
+
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--label" => {
+                i += 1;
+                let Some(value) = args.get(i) else {
+                    eprintln!("Error: --label requires a save label");
+                    std::process::exit(2);
+                };
+                match save::validate_label(value) {
+                    Ok(sanitized) => label = Some(sanitized),
+                    Err(e) => {
+                        eprintln!("Error: {}", e);
+                        std::process::exit(2);
+                    }
+                }
+            }
+            "--format" => {
+                i += 1;
+                format = match args.get(i).map(String::as_str) {
+                    Some("plain") => Format::Plain,
+                    Some("json") => Format::Json,
+                    Some("tmux") => Format::Tmux,
+                    _ => {
+                        eprintln!("Error: --format must be plain, json or tmux");
+                        std::process::exit(2);
+                    }
+                };
+            }
+            "--help" | "-h" => {
+                print_help();
+                return Ok(());
+            }
+            other => {
+                eprintln!("Unknown status option: {}", other);
+                eprintln!("Use 'solaris status --help' for usage information");
+                std::process::exit(2);
+            }
+        }
+        i += 1;
+    }
+
+    let Some(label) = save::resolve_save_label(label.as_deref())? else {
+        eprintln!("No saves found. Create one with: solaris --new <label>");
+        std::process::exit(1);
+    };
+    let Some(status) = read_status(&label, Utc::now())? else {
+        eprintln!(
+            "Error: Save '{}' not found. Use --list to see available saves.",
+            label
+        );
+        std::process::exit(1);
+    };
+
+    match format {
+        Format::Plain => print_plain(&status),
+        Format::Json => print_json(&status)?,
+        Format::Tmux => println!("{}", tmux_line(&status)),
+    }
+    Ok(())
+}
+
+fn print_help() {
+    println!("Usage: solaris status [--label <label>] [--format plain|json|tmux]");
+    println!();
+    println!("Print a save's energy, E/s, chips and next purchase without opening it.");
+    println!("Energy includes offline progress since the last save. The save is not modified.");
+    println!();
+    println!("  --label <label>  Save to report on (default: the last used save)");
+    println!("  --format <fmt>   plain (default), json, or tmux for status-right");
+}
+
+fn read_status(label: &str, now: DateTime<Utc>) -> io::Result<Option<Status>> {
+    let Some(save_data) = save::read_save(label)? else {
+        return Ok(None);
+    };
+
+    let mut app = App::new(label.to_string());
+    app.game = save_data.game_state;
+    let offline = offline_progress(&app.game, save_data.last_save, now);
+    if let Some(report) = offline {
+        app.game.add_energy(report.energy_earned);
+    }
+
+    Ok(Some(Status {
+        label: label.to_string(),
+        running: lock::held_elsewhere(label)?.is_some(),
+        last_save: save_data.last_save,
+        offline,
+        energy: app.game.energy,
+        energy_per_second: app.game.total_energy_per_second(),
+        stellar_chips: app.game.stellar_chips,
+        chips_available: app.game.calculate_potential_stellar_chips(),
+        next: next_purchase(&app),
+    }))
+}
+
+fn print_plain(status: &Status) {
+    println!(
+        "{}{}  (saved {})",
+        status.label,
+        if status.running { " [running]" } else { "" },
+        format_time_ago(status.last_save)
+    );
+    println!("  Energy:  {}", format_cost(status.energy));
+    println!("  Rate:    {}", format_rate(status.energy_per_second));
+    if let Some(offline) = status.offline {
+        println!(
+            "  Offline: +{} over {}",
+            format_cost(offline.energy_earned),
+            format_duration(offline.duration_secs)
+        );
+    }
+    println!(
+        "  Chips:   {} (+{} on ascension)",
+        status.stellar_chips, status.chips_available
+    );
+    if let Some(next) = &status.next {
+        println!(
+            "  Next:    {} ({}), {} {}",
+            next.name,
+            next.kind,
+            format_cost(next.cost),
+            wait_label(next.wait_secs)
+        );
+    }
+}
+
+fn print_json(status: &Status) -> io::Result<()> {
+    let next = status.next.as_ref().map(|next| {
+        serde_json::json!({
+            "name": next.name,
+            "kind": next.kind,
+            "cost": next.cost,
+            "affordable": next.wait_secs == 0.0,
+            "wait_secs": next.wait_secs.is_finite().then_some(next.wait_secs),
+        })
+    });
+    let value = serde_json::json!({
+        "label": status.label,
+        "running": status.running,
+        "last_save": status.last_save,
+        "offline_secs": status.offline.map_or(0, |o| o.duration_secs),
+        "offline_energy": status.offline.map_or(0.0, |o| o.energy_earned),
+        "energy": status.energy,
+        "energy_per_second": status.energy_per_second,
+        "stellar_chips": status.stellar_chips,
+        "chips_available": status.chips_available,
+        "next_purchase": next,
+    });
+    let output = serde_json::to_string_pretty(&value)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    println!("{}", output);
+    Ok(())
+}
+
+/// e.g. `⚛ 4.50M +12.3K/s +3 chips → Fusion Reactor 2m`, colored with tmux style codes
+fn tmux_line(status: &Status) -> String {
+    let mut line = format!(
+        "#[fg=yellow]{} {}#[fg=green] +{}/s",
+        glyphs().energy,
+        format_energy_short(status.energy),
+        format_energy_short(status.energy_per_second)
+    );
+    if status.chips_available > 0 {
+        let chips = if status.chips_available < 1000 {
+            status.chips_available.to_string()
+        } else {
+            format_energy_short(status.chips_available as f64)
+        };
+        line.push_str(&format!(" #[fg=magenta]+{} chips", chips));
+    }
+    if let Some(next) = &status.next {
+        let wait = if next.wait_secs == 0.0 {
+            "now".to_string()
+        } else if next.wait_secs.is_finite() {
+            format_duration(next.wait_secs.ceil() as u64)
+        } else {
+            "-".to_string()
+        };
+        line.push_str(&format!(
+            " #[fg=cyan]{} {} {}",
+            glyphs().arrow,
+            next.name,
+            wait
+        ));
+    }
+    line.push_str("#[default]");
+    line
+}
+
+fn wait_label(wait_secs: f64) -> String {
+    if wait_secs == 0.0 {
+        "affordable now".to_string()
+    } else if wait_secs.is_finite() {
+        format!("affordable in {}", format_duration(wait_secs.ceil() as u64))
+    } else {
+        "not affordable at the current rate".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tmux_line() {
+        let status = Status {
+            label: "main".to_string(),
+            running: false,
+            last_save: Utc::now(),
+            offline: None,
+            energy: 4.5e6,
+            energy_per_second: 12_345.0,
+            stellar_chips: 0,
+            chips_available: 3,
+            next: Some(NextPurchase {
+                name: "Fusion Reactor",
+                kind: "producer",
+                cost: 5e6,
+                wait_secs: 40.5,
+            }),
+        };
+        let line = tmux_line(&status);
+        assert!(line.contains("4.50M"));
+        assert!(line.contains("+12.3K/s"));
+        assert!(line.contains("+3 chips"));
+        assert!(line.contains("Fusion Reactor 41s"));
+        assert!(line.ends_with("#[default]"));
+    }
+}
@@ -0,0 +1,291 @@
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use ratatui::style::{Color, Modifier, Style};
+use serde::{Deserialize, Serialize};
+
+use crate::save::get_data_dir;
+
+/// Names accepted by `--theme` and the `theme` setting besides user theme files
+pub const PRESETS: [&str; 4] = ["default", "solarized", "monochrome", "high-contrast"];
+
+/// Colors for every UI role. Render functions read these instead of naming colors,
+/// so a preset or user file restyles the whole interface.
+///
+/// User themes live in `themes/<name>.json` in the data directory. Colors are
+/// written as names (`"light-blue"`), hex (`"#268bd2"`) or 256-color indices
+/// (`"42"`); missing fields fall back to the default theme.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Theme {
+    /// Regular body text
+    pub text: Color,
+    /// Labels, hints and anything out of reach
+    pub muted: Color,
+    /// Headings, summaries and informational borders
+    pub accent: Color,
+    /// Popups, notifications and the active tab
+    pub highlight: Color,
+    /// Auto-play, prestige and other out-of-the-ordinary values
+    pub special: Color,
+    /// Secondary information such as milestones and the event log
+    pub info: Color,
+    pub affordable: Color,
+    pub unaffordable: Color,
+    /// Rows in sell mode
+    pub selling: Color,
+    pub border_focused: Color,
+    pub border_unfocused: Color,
+    /// Text on a selected row's background
+    pub selected_text: Color,
+    /// Background of popups and overlays
+    pub background: Color,
+    /// Producer colors in the visualization, by tier
+    pub tiers: [Color; 10],
+    /// Energy rings in the visualization, innermost first
+    pub energy_rings: [Color; 4],
+    /// Empty orbit tracks in the visualization
+    pub orbit: Color,
+    /// Background stars in the visualization, brightest first
+    pub stars: [Color; 4],
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            text: Color::White,
+            muted: Color::DarkGray,
+            accent: Color::Cyan,
+            highlight: Color::Yellow,
+            special: Color::Magenta,
+            info: Color::LightBlue,
+            affordable: Color::Green,
+            unaffordable: Color::Red,
+            selling: Color::Yellow,
+            border_focused: Color::Yellow,
+            border_unfocused: Color::DarkGray,
+            selected_text: Color::Black,
+            background: Color::Black,
+            tiers: [
+                Color::Rgb(200, 200, 100), // Solar Panel - pale yellow
+                Color::Rgb(150, 150, 150), // Mining Drone - gray
+                Color::Rgb(255, 140, 0),   // Asteroid Mine - orange
+                Color::Rgb(100, 200, 255), // Orbital Station - light blue
+                Color::Rgb(200, 200, 220), // Lunar Colony - silver
+                Color::Rgb(100, 255, 100), // Planetary Harvester - green
+                Color::Rgb(255, 100, 255), // Fusion Reactor - magenta
+                Color::Rgb(255, 215, 0),   // Dyson Swarm - gold
+                Color::Rgb(255, 255, 100), // Dyson Sphere - bright yellow
+                Color::Rgb(255, 255, 255), // Star Forge - white
+            ],
+            energy_rings: [
+                Color::Rgb(150, 220, 255), // 0-100%: bright cyan
+                Color::Rgb(100, 180, 220), // 100-200%: medium cyan
+                Color::Rgb(60, 140, 180),  // 200-300%: darker cyan
+                Color::Rgb(40, 100, 140),  // 300%+: dark cyan
+            ],
+            orbit: Color::Rgb(40, 40, 50),
+            stars: [
+                Color::White,
+                Color::Gray,
+                Color::Rgb(180, 180, 180),
+                Color::DarkGray,
+            ],
+        }
+    }
+}
+
+impl Theme {
+    /// Palette from https://ethanschoonover.com/solarized/
+    pub fn solarized() -> Self {
+        let base01 = Color::Rgb(0x58, 0x6e, 0x75);
+        let base0 = Color::Rgb(0x83, 0x94, 0x96);
+        let base03 = Color::Rgb(0x00, 0x2b, 0x36);
+        let yellow = Color::Rgb(0xb5, 0x89, 0x00);
+        let orange = Color::Rgb(0xcb, 0x4b, 0x16);
+        let red = Color::Rgb(0xdc, 0x32, 0x2f);
+        let magenta = Color::Rgb(0xd3, 0x36, 0x82);
+        let violet = Color::Rgb(0x6c, 0x71, 0xc4);
+        let blue = Color::Rgb(0x26, 0x8b, 0xd2);
+        let cyan = Color::Rgb(0x2a, 0xa1, 0x98);
+        let green = Color::Rgb(0x85, 0x99, 0x00);
+
+        Self {
+            text: base0,
+            muted: base01,
+            accent: cyan,
+            highlight: yellow,
+            special: magenta,
+            info: blue,
+            affordable: green,
+            unaffordable: red,
+            selling: orange,
+            border_focused: yellow,
+            border_unfocused: base01,
+            selected_text: base03,
+            background: base03,
+            tiers: [
+                yellow, base01, orange, blue, base0, green, magenta, yellow, orange, violet,
+            ],
+            energy_rings: [cyan, blue, violet, base01],
+            orbit: Color::Rgb(0x07, 0x36, 0x42),
+            stars: [Color::Rgb(0x93, 0xa1, 0xa1), base0, base0, base01],
+        }
+    }
+
+    /// No colors at all; selection is shown with reversed video
+    pub fn monochrome() -> Self {
+        Self {
+            text: Color::Reset,
+            muted: Color::Reset,
+            accent: Color::Reset,
+            highlight: Color::Reset,
+            special: Color::Reset,
+            info: Color::Reset,
+            affordable: Color::Reset,
+            unaffordable: Color::Reset,
+            selling: Color::Reset,
+            border_focused: Color::Reset,
+            border_unfocused: Color::Reset,
+            selected_text: Color::Reset,
+            background: Color::Reset,
+            tiers: [Color::Reset; 10],
+            energy_rings: [Color::Reset; 4],
+            orbit: Color::Reset,
+            stars: [Color::Reset; 4],
+        }
+    }
+
+    /// Bright, distinct colors on black for low-vision users and washed-out terminals
+    pub fn high_contrast() -> Self {
+        Self {
+            text: Color::White,
+            muted: Color::Gray,
+            accent: Color::LightCyan,
+            highlight: Color::LightYellow,
+            special: Color::LightMagenta,
+            info: Color::LightBlue,
+            affordable: Color::LightGreen,
+            unaffordable: Color::LightRed,
+            selling: Color::LightYellow,
+            border_focused: Color::White,
+            border_unfocused: Color::Gray,
+            selected_text: Color::Black,
+            background: Color::Black,
+            tiers: [
+                Color::LightYellow,
+                Color::White,
+                Color::LightRed,
+                Color::LightCyan,
+                Color::Gray,
+                Color::LightGreen,
+                Color::LightMagenta,
+                Color::Yellow,
+                Color::LightYellow,
+                Color::White,
+            ],
+            energy_rings: [Color::LightCyan, Color::Cyan, Color::LightBlue, Color::Blue],
+            orbit: Color::DarkGray,
+            stars: [Color::White, Color::Gray, Color::Gray, Color::DarkGray],
+        }
+    }
+
+    pub fn preset(name: &str) -> Option<Self> {
+        match name {
+            "default" => Some(Self::default()),
+            "solarized" => Some(Self::solarized()),
+            "monochrome" => Some(Self::monochrome()),
+            "high-contrast" => Some(Self::high_contrast()),
+            _ => None,
+        }
+    }
+
+    /// Resolve a theme name to a preset or a user theme file. `NO_COLOR`
+    /// (https://no-color.org) overrides whatever was chosen.
+    pub fn load(name: &str) -> io::Result<Self> {
+        if no_color_requested() {
+            return Ok(Self::monochrome());
+        }
+        if let Some(theme) = Self::preset(name) {
+            return Ok(theme);
+        }
+
+        let path = get_theme_path(name)?;
+        if !path.exists() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!(
+                    "Unknown theme '{}' (presets: {}; user themes go in {})",
+                    name,
+                    PRESETS.join(", "),
+                    path.display()
+                ),
+            ));
+        }
+        let json = fs::read_to_string(&path)?;
+        serde_json::from_str(&json).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// Style for the selected row of a list, drawn on `background`.
+    /// Without colors, the row is reversed instead.
+    pub fn selected(&self, background: Color) -> Style {
+        if background == Color::Reset {
+            Style::default().add_modifier(Modifier::REVERSED)
+        } else {
+            Style::default().fg(self.selected_text).bg(background)
+        }
+    }
+
+    pub fn border(&self, focused: bool) -> Color {
+        if focused {
+            self.border_focused
+        } else {
+            self.border_unfocused
+        }
+    }
+
+    pub fn tier_color(&self, tier: usize) -> Color {
+        self.tiers.get(tier).copied().unwrap_or(self.text)
+    }
+}
+
+fn no_color_requested() -> bool {
+    std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty())
+}
+
+fn get_theme_path(name: &str) -> io::Result<PathBuf> {
+    Ok(get_data_dir()?
+        .join("themes")
+        .join(format!("{}.json", name)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_presets_resolve() {
+        for name in PRESETS {
+            assert!(Theme::preset(name).is_some(), "missing preset {}", name);
+        }
+        assert!(Theme::preset("neon").is_none());
+    }
+
+    #[test]
+    fn test_partial_theme_file_uses_defaults() {
+        let theme: Theme =
+            serde_json::from_str(r##"{"accent": "#268bd2", "muted": "light-blue"}"##).unwrap();
+        assert_eq!(theme.accent, Color::Rgb(0x26, 0x8b, 0xd2));
+        assert_eq!(theme.muted, Color::LightBlue);
+        assert_eq!(theme.affordable, Theme::default().affordable);
+    }
+
+    #[test]
+    fn test_monochrome_selection_reverses() {
+        let theme = Theme::monochrome();
+        let style = theme.selected(theme.affordable);
+        assert!(style.add_modifier.contains(Modifier::REVERSED));
+        assert_eq!(style.bg, None);
+    }
+}
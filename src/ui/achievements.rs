@@ -2,7 +2,8 @@ use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Tabs};
 
 use crate::app::App;
-use crate::game::Achievement;
+use crate::game::{Achievement, AchievementCategory, AchievementProgress};
+use crate::glyphs::glyphs;
 
 pub fn render(frame: &mut Frame, area: Rect, app: &App) {
     // Create a centered popup
@@ -12,10 +13,11 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
     frame.render_widget(Clear, popup_area);
 
     let block = Block::default()
-        .title(" Achievements [Tab to switch, A to close] ")
+        .title(" Achievements [Tab/1-9 to switch, X to close] ")
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::LightYellow))
-        .style(Style::default().bg(Color::Black));
+        .border_set(glyphs().border)
+        .border_style(Style::default().fg(app.theme.highlight))
+        .style(Style::default().bg(app.theme.background));
 
     let inner = block.inner(popup_area);
     frame.render_widget(block, popup_area);
@@ -41,23 +43,18 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
 }
 
 fn render_tabs(frame: &mut Frame, area: Rect, app: &App) {
-    let tab_titles = vec![
-        "All",
-        "Producers",
-        "Production",
-        "Lifetime",
-        "Clicks",
-        "Upgrades",
-        "Time",
-        "Prestige",
-    ];
+    let tab_titles: Vec<String> = std::iter::once("All")
+        .chain(AchievementCategory::ALL.iter().map(|c| c.name()))
+        .enumerate()
+        .map(|(i, name)| format!("{} {}", i + 1, name))
+        .collect();
 
     let tabs = Tabs::new(tab_titles)
         .select(app.achievement_tab)
-        .style(Style::default().fg(Color::White))
+        .style(Style::default().fg(app.theme.text))
         .highlight_style(
             Style::default()
-                .fg(Color::Yellow)
+                .fg(app.theme.highlight)
                 .add_modifier(Modifier::BOLD),
         )
         .divider(" | ");
@@ -69,32 +66,64 @@ fn render_stats(frame: &mut Frame, area: Rect, app: &App) {
     let total = Achievement::all().len();
     let unlocked = app.game.achievements_unlocked.len();
     let bonus = (app.game.get_achievement_multiplier() - 1.0) * 100.0;
-
-    let stats_text = format!(
-        "  Unlocked: {}/{} ({:.1}%)  |  Production Bonus: +{:.1}%",
+    let secrets = Achievement::all().iter().filter(|a| a.hidden);
+    let secret_total = secrets.clone().count();
+    let secret_found = secrets
+        .filter(|a| app.game.achievements_unlocked.contains(&a.id))
+        .count();
+
+    let overall = format!(
+        "  Unlocked: {}/{} ({:.1}%)  |  Secrets: {}/{}  |  Production Bonus: +{:.1}%",
         unlocked,
         total,
         (unlocked as f64 / total as f64) * 100.0,
+        secret_found,
+        secret_total,
         bonus
     );
 
-    let paragraph = Paragraph::new(stats_text).style(Style::default().fg(Color::Cyan));
+    // Completion and bonus share of the selected category
+    let category_line = match app.achievement_tab_category() {
+        Some(category) => {
+            let (cat_unlocked, cat_total) = app.game.achievement_category_progress(category);
+            let (multiplier, essence) = app.game.achievement_category_bonus(category);
+            format!(
+                "  {}: {}/{}  |  Production x{:.3}  |  Stellar Essence +{:.0}%",
+                category.name(),
+                cat_unlocked,
+                cat_total,
+                multiplier,
+                essence * 100.0
+            )
+        }
+        None => {
+            let parts: Vec<String> = AchievementCategory::ALL
+                .iter()
+                .map(|&category| {
+                    let (cat_unlocked, cat_total) =
+                        app.game.achievement_category_progress(category);
+                    format!("{} {}/{}", category.name(), cat_unlocked, cat_total)
+                })
+                .collect();
+            format!("  {}", parts.join("  "))
+        }
+    };
+
+    let paragraph = Paragraph::new(vec![
+        Line::from(overall).style(Style::default().fg(app.theme.accent)),
+        Line::from(category_line).style(Style::default().fg(app.theme.muted)),
+    ]);
     frame.render_widget(paragraph, area);
 }
 
 fn render_achievement_list(frame: &mut Frame, area: Rect, app: &App) {
-    let all_achievements = Achievement::all();
     let unlocked_ids = &app.game.achievements_unlocked;
 
-    // Filter achievements based on selected tab
-    let filtered: Vec<&Achievement> = all_achievements
-        .iter()
-        .filter(|a| matches_tab(a, app.achievement_tab))
-        .collect();
+    let filtered = tab_achievements(app);
 
     if filtered.is_empty() {
         let paragraph = Paragraph::new("\n  No achievements in this category.")
-            .style(Style::default().fg(Color::DarkGray));
+            .style(Style::default().fg(app.theme.muted));
         frame.render_widget(paragraph, area);
         return;
     }
@@ -113,24 +142,26 @@ fn render_achievement_list(frame: &mut Frame, area: Rect, app: &App) {
             };
             let desc = if is_unlocked {
                 achievement.description.to_string()
+            } else if achievement.hidden {
+                "???".to_string()
             } else {
                 get_hint(achievement)
             };
 
-            let line = format!(" {} {:<30} {}", status, name, desc);
-
-            let style = if is_unlocked {
-                if i == app.selected_achievement {
-                    Style::default().fg(Color::Black).bg(Color::Yellow)
-                } else {
-                    Style::default().fg(Color::Green)
-                }
+            // Live progress for locked achievements whose requirement is known
+            let progress = if is_unlocked || achievement.hidden {
+                String::new()
             } else {
-                if i == app.selected_achievement {
-                    Style::default().fg(Color::Black).bg(Color::DarkGray)
-                } else {
-                    Style::default().fg(Color::DarkGray)
-                }
+                format_progress(&app.game.achievement_progress(&achievement.requirement))
+            };
+
+            let line = format!(" {} {:<30} {:<44} {}", status, name, desc, progress);
+
+            let style = match (is_unlocked, i == app.selected_achievement) {
+                (true, true) => app.theme.selected(app.theme.highlight),
+                (true, false) => Style::default().fg(app.theme.affordable),
+                (false, true) => app.theme.selected(app.theme.muted),
+                (false, false) => Style::default().fg(app.theme.muted),
             };
 
             ListItem::new(line).style(style)
@@ -143,10 +174,18 @@ fn render_achievement_list(frame: &mut Frame, area: Rect, app: &App) {
         .constraints([Constraint::Length(1), Constraint::Min(1)])
         .split(area);
 
-    let header = format!(" {:<3} {:<30} {}", "", "Achievement", "Requirement");
+    let sort_label = if app.achievement_sort_closest {
+        "Progress [s: closest first]"
+    } else {
+        "Progress [s: sort]"
+    };
+    let header = format!(
+        " {:<3} {:<30} {:<44} {}",
+        "", "Achievement", "Requirement", sort_label
+    );
     let header_widget = Paragraph::new(header).style(
         Style::default()
-            .fg(Color::Cyan)
+            .fg(app.theme.accent)
             .add_modifier(Modifier::BOLD),
     );
     frame.render_widget(header_widget, chunks[0]);
@@ -160,20 +199,56 @@ fn render_achievement_list(frame: &mut Frame, area: Rect, app: &App) {
     frame.render_stateful_widget(list, chunks[1], &mut state);
 }
 
-fn matches_tab(achievement: &Achievement, tab: usize) -> bool {
-    use crate::game::AchievementRequirement::*;
+/// Achievements on the current tab, in display order. In "closest first" mode, locked
+/// achievements come first, most complete at the top; secrets and unlocked ones follow.
+fn tab_achievements(app: &App) -> Vec<&'static Achievement> {
+    let mut achievements: Vec<&'static Achievement> = Achievement::all()
+        .iter()
+        .filter(|a| matches_tab(a, app))
+        .collect();
 
-    match tab {
-        0 => true,                                                                 // All
-        1 => matches!(achievement.requirement, ProducerCount { .. }),              // Producers
-        2 => matches!(achievement.requirement, TotalEnergyPerSecond(_)),           // Production
-        3 => matches!(achievement.requirement, TotalEnergyEarned(_)),              // Lifetime
-        4 => matches!(achievement.requirement, TotalClicks(_)),                    // Clicks
-        5 => matches!(achievement.requirement, UpgradesPurchased(_)),              // Upgrades
-        6 => matches!(achievement.requirement, TimePlayed(_)),                     // Time
-        7 => matches!(achievement.requirement, Ascensions(_) | TotalProducers(_)), // Prestige
-        _ => true,
+    if app.achievement_sort_closest {
+        let unlocked = |a: &Achievement| app.game.achievements_unlocked.contains(&a.id);
+        let mut keyed: Vec<(bool, bool, f64, &'static Achievement)> = achievements
+            .into_iter()
+            .map(|a| {
+                let fraction = app.game.achievement_progress(&a.requirement).fraction();
+                (unlocked(a), a.hidden, fraction, a)
+            })
+            .collect();
+        // Stable sort keeps definition order between equally complete entries
+        keyed.sort_by(|x, y| {
+            (x.0, x.1)
+                .cmp(&(y.0, y.1))
+                .then(y.2.partial_cmp(&x.2).unwrap_or(std::cmp::Ordering::Equal))
+        });
+        achievements = keyed.into_iter().map(|(_, _, _, a)| a).collect();
     }
+
+    achievements
+}
+
+fn format_progress(progress: &AchievementProgress) -> String {
+    const BAR_WIDTH: usize = 10;
+    let fraction = progress.fraction();
+    let filled = ((fraction * BAR_WIDTH as f64) as usize).min(BAR_WIDTH);
+    let mut text = format!(
+        "[{}{}] {:>3.0}% {}/{}",
+        glyphs().bar_filled.repeat(filled),
+        glyphs().bar_empty.repeat(BAR_WIDTH - filled),
+        (fraction * 100.0).floor(),
+        format_number(progress.current.min(progress.target)),
+        format_number(progress.target)
+    );
+    if let Some(eta) = progress.eta_secs {
+        text.push_str(&format!("  ETA {}", crate::format::format_duration(eta)));
+    }
+    text
+}
+
+fn matches_tab(achievement: &Achievement, app: &App) -> bool {
+    app.achievement_tab_category()
+        .is_none_or(|category| achievement.category == category)
 }
 
 fn get_hint(achievement: &Achievement) -> String {
@@ -188,6 +263,17 @@ fn get_hint(achievement: &Achievement) -> String {
         TimePlayed(secs) => format!("Play for {}", format_duration(secs)),
         TotalProducers(count) => format!("Own {} total producers", count),
         Ascensions(count) => format!("Ascend {} times", count),
+        SpeedEnergyPerSecond { rate, within_secs } => format!(
+            "Reach {} E/s within {} of a run",
+            format_number(rate),
+            format_duration(within_secs)
+        ),
+        SoleProducer { count, .. } => format!("Own {} of one producer and nothing else", count),
+        ChipsInOneAscension(chips) => format!("Earn {} chips in one ascension", chips),
+        AllPrestigeUpgrades => "Own every prestige upgrade".to_string(),
+        ClickStreak(clicks) => format!("Click {} times without a 1s pause", clicks),
+        EveryProducer(count) => format!("Own {} of every producer", count),
+        AllCountBonuses => "Reach every producer count bonus".to_string(),
     }
 }
 
@@ -248,6 +334,6 @@ fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
 pub fn filtered_achievement_count(app: &App) -> usize {
     Achievement::all()
         .iter()
-        .filter(|a| matches_tab(a, app.achievement_tab))
+        .filter(|a| matches_tab(a, app))
         .count()
 }
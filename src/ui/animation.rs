@@ -1,3 +1,5 @@
+use crate::glyphs::glyphs;
+
 /// Star animation type
 #[derive(Clone, Copy, PartialEq)]
 pub enum StarKind {
@@ -83,8 +85,9 @@ impl StarField {
 
     /// Get the character for a star based on kind, frame and its phase
     pub fn star_char(kind: StarKind, phase: u8, frame: u64) -> char {
-        const STAR_CHARS: &[char] = &['·', '✢', '✳', '∗', '✻', '✽'];
-        const STATIC_CHARS: &[char] = &['·', '∗', '✧'];
+        let glyphs = glyphs();
+        let star_chars = &glyphs.twinkle;
+        let static_chars = &glyphs.still_stars;
         const SEQUENCE: &[(usize, u32)] = &[
             (0, 3), // '·' - hold 3 ticks
             (1, 1), // '✢'
@@ -102,7 +105,7 @@ impl StarField {
         match kind {
             StarKind::Static => {
                 // Static stars don't animate - use phase to pick a fixed character
-                STATIC_CHARS[(phase as usize) % STATIC_CHARS.len()]
+                static_chars[(phase as usize) % static_chars.len()]
             }
             StarKind::SlowTwinkle | StarKind::FastTwinkle => {
                 // SlowTwinkle: divide frame by 4 (~930ms cycle)
@@ -120,10 +123,10 @@ impl StarField {
                 for &(char_idx, weight) in SEQUENCE {
                     accumulated += weight;
                     if (tick as u32) < accumulated {
-                        return STAR_CHARS[char_idx];
+                        return star_chars[char_idx];
                     }
                 }
-                STAR_CHARS[0]
+                star_chars[0]
             }
         }
     }
@@ -1,7 +1,9 @@
 use ratatui::prelude::*;
 use ratatui::widgets::Paragraph;
 
-pub fn render(frame: &mut Frame, area: Rect) {
+use crate::theme::Theme;
+
+pub fn render(frame: &mut Frame, area: Rect, theme: &Theme) {
     let fake_output = r#"$ cargo build --release
    Compiling solaris v0.1.0 (/home/user/projects/solaris)
     Finished release [optimized] target(s) in 2.34s
@@ -36,7 +38,7 @@ nothing to commit, working tree clean
 $ _"#;
 
     let paragraph =
-        Paragraph::new(fake_output).style(Style::default().fg(Color::White).bg(Color::Black));
+        Paragraph::new(fake_output).style(Style::default().fg(theme.text).bg(theme.background));
 
     frame.render_widget(paragraph, area);
 }
@@ -0,0 +1,208 @@
+use ratatui::prelude::*;
+use ratatui::widgets::{List, ListItem, ListState, Paragraph};
+
+use crate::app::{App, Panel};
+use crate::format::{format_cost, format_duration, format_energy, format_rate};
+use crate::game::{Achievement, Upgrade};
+use crate::glyphs::{glyphs, icon};
+
+/// Smallest area the compact layout can draw into
+pub const MIN_WIDTH: u16 = 20;
+pub const MIN_HEIGHT: u16 = 4;
+
+/// Compact layout for small terminals: a one-line header, the active panel in
+/// abbreviated form, and a footer naming the panels `h`/`l` switch between
+pub fn render(frame: &mut Frame, area: Rect, app: &mut App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1), // Header
+            Constraint::Min(1),    // Active panel
+            Constraint::Length(1), // Panel switcher
+        ])
+        .split(area);
+
+    render_header(frame, chunks[0], app);
+
+    match app.active_panel {
+        Panel::Producers => render_producers(frame, chunks[1], app),
+        Panel::Upgrades => render_upgrades(frame, chunks[1], app),
+        Panel::Stats => render_stats(frame, chunks[1], app),
+        Panel::Visualization => super::visualization::render(frame, chunks[1], app, true),
+    }
+
+    render_switcher(frame, chunks[2], app);
+}
+
+fn render_header(frame: &mut Frame, area: Rect, app: &App) {
+    let mut text = format!(
+        "{} {}  {}",
+        glyphs().energy,
+        format_energy(app.game.energy),
+        format_rate(app.game.total_energy_per_second())
+    );
+    if app.auto_mode {
+        text.push_str(if app.auto_paused {
+            "  AUTO||"
+        } else {
+            "  AUTO"
+        });
+    }
+    if app.read_only {
+        text.push_str("  R/O");
+    }
+    let paragraph = Paragraph::new(text).style(
+        Style::default()
+            .fg(app.theme.accent)
+            .add_modifier(Modifier::BOLD),
+    );
+    frame.render_widget(paragraph, area);
+}
+
+fn render_producers(frame: &mut Frame, area: Rect, app: &App) {
+    let visible = app.game.visible_producers();
+    let selected = app.selected_producer.min(visible.len().saturating_sub(1));
+
+    let items: Vec<ListItem> = visible
+        .iter()
+        .enumerate()
+        .map(|(i, (_, producer))| {
+            let owned = app.game.producer_count(producer.id);
+            let quantity = if app.sell_mode {
+                app.calculate_sell_quantity(producer)
+            } else {
+                app.get_buy_quantity_for_producer(producer)
+            };
+            let cost = super::producers::display_price(app, producer);
+            let can_afford = quantity > 0 && (app.sell_mode || app.game.energy >= cost);
+
+            // Only the selected row has room for its price
+            let line = if i == selected {
+                let action = if app.sell_mode { "sell" } else { "buy" };
+                format!(
+                    "{} {:>6}  {} {}",
+                    icon(producer),
+                    owned,
+                    action,
+                    format_cost(cost)
+                )
+            } else {
+                format!("{} {:>6}", icon(producer), owned)
+            };
+
+            let style = if i == selected {
+                app.theme.selected(if can_afford {
+                    app.theme.affordable
+                } else {
+                    app.theme.unaffordable
+                })
+            } else if can_afford {
+                Style::default().fg(app.theme.affordable)
+            } else {
+                Style::default().fg(app.theme.muted)
+            };
+
+            ListItem::new(line).style(style)
+        })
+        .collect();
+
+    let mut state = ListState::default();
+    state.select(Some(selected));
+    frame.render_stateful_widget(List::new(items), area, &mut state);
+}
+
+fn render_upgrades(frame: &mut Frame, area: Rect, app: &App) {
+    let available = app.game.available_upgrades();
+    if available.is_empty() {
+        frame.render_widget(
+            Paragraph::new("No upgrades yet").style(Style::default().fg(app.theme.muted)),
+            area,
+        );
+        return;
+    }
+
+    let width = area.width as usize;
+    let items: Vec<ListItem> = available
+        .iter()
+        .enumerate()
+        .map(|(i, upgrade)| {
+            let cost = app.game.get_upgrade_cost(upgrade);
+            let cost_text = format_cost(cost);
+            let name_width = width.saturating_sub(cost_text.chars().count() + 1);
+            let name: String = upgrade.name.chars().take(name_width).collect();
+            let line = format!("{:<name_width$} {}", name, cost_text);
+
+            let can_afford = app.game.energy >= cost;
+            let style = if i == app.selected_upgrade {
+                app.theme.selected(if can_afford {
+                    app.theme.affordable
+                } else {
+                    app.theme.unaffordable
+                })
+            } else if can_afford {
+                Style::default().fg(app.theme.affordable)
+            } else {
+                Style::default().fg(app.theme.muted)
+            };
+
+            ListItem::new(line).style(style)
+        })
+        .collect();
+
+    let mut state = ListState::default();
+    state.select(Some(
+        app.selected_upgrade.min(available.len().saturating_sub(1)),
+    ));
+    frame.render_stateful_widget(List::new(items), area, &mut state);
+}
+
+fn render_stats(frame: &mut Frame, area: Rect, app: &App) {
+    let game = &app.game;
+    let lines = [
+        format!("Earned  {}", format_energy(game.total_energy_earned)),
+        format!("Played  {}", format_duration(game.time_played_seconds())),
+        format!("Owned   {}", game.total_producers_owned()),
+        format!(
+            "Upgr.   {}/{}",
+            game.upgrades_purchased.len(),
+            Upgrade::all().len()
+        ),
+        format!(
+            "Achv.   {}/{}",
+            game.achievements_unlocked.len(),
+            Achievement::all().len()
+        ),
+        format!(
+            "Chips   {} (+{})",
+            game.stellar_chips,
+            game.calculate_potential_stellar_chips()
+        ),
+    ];
+    let text: Vec<Line> = lines.into_iter().map(Line::from).collect();
+    frame.render_widget(Paragraph::new(text), area);
+}
+
+fn render_switcher(frame: &mut Frame, area: Rect, app: &App) {
+    let mut spans = vec![Span::styled("h/l ", Style::default().fg(app.theme.muted))];
+    for (i, (panel, label)) in [
+        (Panel::Producers, "Prod"),
+        (Panel::Upgrades, "Upgr"),
+        (Panel::Stats, "Stats"),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        if i > 0 {
+            spans.push(Span::styled("|", Style::default().fg(app.theme.muted)));
+        }
+        let style = if app.active_panel == panel {
+            Style::default()
+                .fg(app.theme.highlight)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(app.theme.muted)
+        };
+        spans.push(Span::styled(label, style));
+    }
+    frame.render_widget(Paragraph::new(Line::from(spans)), area);
+}
@@ -3,6 +3,7 @@ use ratatui::widgets::{Block, Borders, Paragraph};
 
 use crate::app::App;
 use crate::format::{format_energy, format_rate};
+use crate::glyphs::glyphs;
 
 pub fn render(frame: &mut Frame, area: Rect, app: &App) {
     let energy = format_energy(app.game.energy);
@@ -27,7 +28,21 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
     } else {
         String::new()
     };
-    let text = format!("  Energy: {} ⚛    Rate: {}{}", energy, rate, auto_indicator);
+    let queue_indicator = if app.game.purchase_queue.is_empty() {
+        String::new()
+    } else {
+        format!("    Queue: {}", app.game.purchase_queue.len())
+    };
+    let read_only_indicator = if app.read_only { "  [READ-ONLY]" } else { "" };
+    let text = format!(
+        "  Energy: {} {}    Rate: {}{}{}{}",
+        energy,
+        glyphs().energy,
+        rate,
+        queue_indicator,
+        auto_indicator,
+        read_only_indicator
+    );
 
     let title = if app.auto_mode {
         if app.auto_paused {
@@ -41,23 +56,24 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
 
     let border_color = if app.auto_mode {
         if app.auto_paused {
-            Color::Yellow
+            app.theme.highlight
         } else {
-            Color::Magenta
+            app.theme.special
         }
     } else {
-        Color::Cyan
+        app.theme.accent
     };
 
     let block = Block::default()
         .title(title)
         .title_alignment(Alignment::Center)
         .borders(Borders::ALL)
+        .border_set(glyphs().border)
         .border_style(Style::default().fg(border_color));
 
     let paragraph = Paragraph::new(text)
         .block(block)
-        .style(Style::default().fg(Color::White));
+        .style(Style::default().fg(app.theme.text));
 
     frame.render_widget(paragraph, area);
 }
@@ -2,6 +2,7 @@ use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Clear, Paragraph};
 
 use crate::app::{App, LayoutMode};
+use crate::glyphs::glyphs;
 
 pub fn render(frame: &mut Frame, area: Rect, app: &App) {
     if !app.show_help {
@@ -9,7 +10,7 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
     }
 
     let help_text = match app.layout_mode {
-        LayoutMode::Single => {
+        LayoutMode::Single | LayoutMode::Compact => {
             format!(
                 r#"
    SOLARIS - Keyboard Shortcuts        Save: {}
@@ -27,11 +28,18 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
    v             Toggle Visualization panel
    a             Toggle Ascension panel
    x             Toggle Achievements panel
+   w             Toggle Purchase Queue
+   L             Toggle Event Log
+   o             Save slots (switch, copy, rename, delete)
+   h / l         Switch panel (compact layout)
 
     Actions
     -------
     Space         Manual mine
-    Tab           Cycle buy amount (1/10/Max)
+    Tab           Cycle buy amount (1/10/25/100/Next/Max)
+    n             Type a custom buy amount
+    S             Toggle sell mode (in Producers)
+    e             Add selected item to queue
     d             Toggle producer detail (in Producers)
     i             Show purchase hint
     b             Toggle boss mode
@@ -44,7 +52,12 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
                 app.save_label
             )
         }
-        LayoutMode::TwoColumn => {
+        LayoutMode::TwoColumn | LayoutMode::ThreeColumn => {
+            let stats_action = if app.layout_mode == LayoutMode::TwoColumn {
+                "Swap Stats / Visualization"
+            } else {
+                "Focus Stats panel"
+            };
             format!(
                 r#"
     SOLARIS - Keyboard Shortcuts       Save: {}
@@ -62,13 +75,21 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
     p             Focus Producers panel
     u             Focus Upgrades panel
     v             Focus Visualization panel
+    s             {}
+    [ / ]         Narrow / widen Producers column
     a             Toggle Ascension panel
     x             Toggle Achievements panel
+    w             Toggle Purchase Queue
+    L             Toggle Event Log
+    o             Save slots (switch, copy, rename, delete)
 
     Actions
     -------
     Space         Manual mine
-    Tab           Cycle buy amount (1/10/Max)
+    Tab           Cycle buy amount (1/10/25/100/Next/Max)
+    n             Type a custom buy amount
+    S             Toggle sell mode (in Producers)
+    e             Add selected item to queue
     d             Toggle producer detail (in Producers)
     i             Show purchase hint
     b             Toggle boss mode
@@ -78,14 +99,14 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
 
     Press ? to close
  "#,
-                app.save_label
+                app.save_label, stats_action
             )
         }
     };
 
     // Calculate popup size and position (clamp to fit terminal)
     let popup_width = 52.min(area.width.saturating_sub(4));
-    let popup_height = 26.min(area.height.saturating_sub(4));
+    let popup_height = 35.min(area.height.saturating_sub(4));
 
     // Ensure we have minimum viable size
     if popup_width < 20 || popup_height < 10 {
@@ -109,11 +130,12 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
     let block = Block::default()
         .title(" Help ")
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Cyan));
+        .border_set(glyphs().border)
+        .border_style(Style::default().fg(app.theme.accent));
 
     let paragraph = Paragraph::new(help_text)
         .block(block)
-        .style(Style::default().fg(Color::White));
+        .style(Style::default().fg(app.theme.text));
 
     frame.render_widget(paragraph, popup_area);
 }
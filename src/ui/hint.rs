@@ -2,6 +2,7 @@ use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Clear, Paragraph};
 
 use crate::app::App;
+use crate::glyphs::glyphs;
 
 pub fn render(frame: &mut Frame, area: Rect, app: &App) {
     let lines = match &app.hint_message {
@@ -36,11 +37,12 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
     let block = Block::default()
         .title(" Hint (i) ")
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Cyan));
+        .border_set(glyphs().border)
+        .border_style(Style::default().fg(app.theme.accent));
 
     let paragraph = Paragraph::new(content)
         .block(block)
-        .style(Style::default().fg(Color::White));
+        .style(Style::default().fg(app.theme.text));
 
     frame.render_widget(paragraph, popup_area);
 }
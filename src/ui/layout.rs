@@ -1,18 +1,31 @@
 use ratatui::prelude::*;
 
 use crate::app::{App, LayoutMode};
+use crate::settings::LayoutSettings;
 
 pub const MIN_WIDTH_TWO_COLUMN: u16 = 130;
+pub const MIN_WIDTH_THREE_COLUMN: u16 = 190;
 
 pub struct LayoutChunks {
     pub header: Rect,
     pub left_panel: Rect,
     pub right_top: Option<Rect>,
     pub right_bottom: Option<Rect>,
+    /// Stats column in the three-column layout
+    pub third_column: Option<Rect>,
 }
 
-pub fn determine_layout_mode(width: u16) -> LayoutMode {
-    if width >= MIN_WIDTH_TWO_COLUMN {
+/// Below this size the full UI doesn't fit and the compact layout is used
+pub const MIN_WIDTH_FULL: u16 = 60;
+pub const MIN_HEIGHT_FULL: u16 = 20;
+
+pub fn determine_layout_mode(area: Rect) -> LayoutMode {
+    let width = area.width;
+    if width < MIN_WIDTH_FULL || area.height < MIN_HEIGHT_FULL {
+        LayoutMode::Compact
+    } else if width >= MIN_WIDTH_THREE_COLUMN {
+        LayoutMode::ThreeColumn
+    } else if width >= MIN_WIDTH_TWO_COLUMN {
         LayoutMode::TwoColumn
     } else {
         LayoutMode::Single
@@ -31,32 +44,87 @@ pub fn create_layout(area: Rect, app: &App) -> LayoutChunks {
     let header = chunks[0];
     let main = chunks[1];
 
+    let split = app.settings.layout.clamped();
+
     match app.layout_mode {
-        LayoutMode::Single => LayoutChunks {
+        // The compact layout draws its own single area
+        LayoutMode::Single | LayoutMode::Compact => LayoutChunks {
             header,
             left_panel: main,
             right_top: None,
             right_bottom: None,
+            third_column: None,
         },
         LayoutMode::TwoColumn => {
-            // Split main horizontally: left (Producers) | right (Viz + Upgrades)
+            // Split main horizontally: left (Producers) | right (Viz or Stats + Upgrades)
+            let horizontal = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([
+                    Constraint::Percentage(split.left_percent),
+                    Constraint::Percentage(100 - split.left_percent),
+                ])
+                .split(main);
+
+            let (right_top, right_bottom) = split_right_column(horizontal[1], split);
+
+            LayoutChunks {
+                header,
+                left_panel: horizontal[0],
+                right_top: Some(right_top),
+                right_bottom: Some(right_bottom),
+                third_column: None,
+            }
+        }
+        LayoutMode::ThreeColumn => {
+            // Producers | Viz + Upgrades | Stats, with the producer split applied
+            // to the space left over after the Stats column
+            let rest = 100 - split.stats_percent;
+            let left = rest * split.left_percent / 100;
             let horizontal = Layout::default()
                 .direction(Direction::Horizontal)
-                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .constraints([
+                    Constraint::Percentage(left),
+                    Constraint::Percentage(rest - left),
+                    Constraint::Percentage(split.stats_percent),
+                ])
                 .split(main);
 
-            // Split right side vertically: top (Visualization) | bottom (Upgrades)
-            let right_vertical = Layout::default()
-                .direction(Direction::Vertical)
-                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-                .split(horizontal[1]);
+            let (right_top, right_bottom) = split_right_column(horizontal[1], split);
 
             LayoutChunks {
                 header,
                 left_panel: horizontal[0],
-                right_top: Some(right_vertical[0]),
-                right_bottom: Some(right_vertical[1]),
+                right_top: Some(right_top),
+                right_bottom: Some(right_bottom),
+                third_column: Some(horizontal[2]),
             }
         }
     }
 }
+
+/// Split a column vertically: top (Visualization or Stats) | bottom (Upgrades)
+fn split_right_column(area: Rect, split: LayoutSettings) -> (Rect, Rect) {
+    let vertical = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage(split.right_top_percent),
+            Constraint::Percentage(100 - split.right_top_percent),
+        ])
+        .split(area);
+    (vertical[0], vertical[1])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_layout_mode_thresholds() {
+        let mode = |w, h| determine_layout_mode(Rect::new(0, 0, w, h));
+        assert_eq!(mode(40, 12), LayoutMode::Compact);
+        assert_eq!(mode(200, 15), LayoutMode::Compact);
+        assert_eq!(mode(80, 24), LayoutMode::Single);
+        assert_eq!(mode(140, 40), LayoutMode::TwoColumn);
+        assert_eq!(mode(200, 50), LayoutMode::ThreeColumn);
+    }
+}
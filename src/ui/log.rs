@@ -0,0 +1,146 @@
+use chrono::Local;
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Tabs};
+
+use crate::app::App;
+use crate::game::{EventKind, EVENT_LOG_CAPACITY};
+use crate::glyphs::glyphs;
+use crate::theme::Theme;
+
+pub fn render(frame: &mut Frame, area: Rect, app: &App) {
+    // Create a centered popup
+    let popup_area = centered_rect(80, 80, area);
+
+    // Clear the background
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(" Event Log [Tab to filter, L to close] ")
+        .borders(Borders::ALL)
+        .border_set(glyphs().border)
+        .border_style(Style::default().fg(app.theme.info))
+        .style(Style::default().bg(app.theme.background));
+
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2), // Filter tabs
+            Constraint::Length(2), // Summary
+            Constraint::Min(1),    // Entries
+        ])
+        .split(inner);
+
+    render_filter_tabs(frame, chunks[0], app);
+
+    let log = &app.game.event_log;
+    let entries: Vec<_> = log.newest_first(app.log_filter).collect();
+
+    let summary = format!(
+        "  {} shown  |  {}/{} entries kept, newest first",
+        entries.len(),
+        log.len(),
+        EVENT_LOG_CAPACITY
+    );
+    frame.render_widget(
+        Paragraph::new(summary).style(Style::default().fg(app.theme.accent)),
+        chunks[1],
+    );
+
+    if entries.is_empty() {
+        let paragraph =
+            Paragraph::new("\n  Nothing logged yet.").style(Style::default().fg(app.theme.muted));
+        frame.render_widget(paragraph, chunks[2]);
+        return;
+    }
+
+    let items: Vec<ListItem> = entries
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let time = entry.time.with_timezone(&Local).format("%m-%d %H:%M:%S");
+            let repeat = if entry.repeat > 1 {
+                format!(" (x{})", entry.repeat)
+            } else {
+                String::new()
+            };
+            let line = format!(
+                " {}  {:<12} {}{}",
+                time,
+                entry.kind.name(),
+                entry.message,
+                repeat
+            );
+
+            let style = if i == app.selected_log_entry {
+                app.theme.selected(app.theme.info)
+            } else {
+                Style::default().fg(kind_color(entry.kind, &app.theme))
+            };
+
+            ListItem::new(line).style(style)
+        })
+        .collect();
+
+    let list = List::new(items);
+    let mut state = ListState::default();
+    state.select(Some(
+        app.selected_log_entry.min(entries.len().saturating_sub(1)),
+    ));
+    frame.render_stateful_widget(list, chunks[2], &mut state);
+}
+
+fn render_filter_tabs(frame: &mut Frame, area: Rect, app: &App) {
+    let titles: Vec<&str> = std::iter::once("All")
+        .chain(EventKind::ALL.iter().map(|k| k.name()))
+        .collect();
+    let selected = match app.log_filter {
+        None => 0,
+        Some(kind) => EventKind::ALL.iter().position(|&k| k == kind).unwrap_or(0) + 1,
+    };
+
+    let tabs = Tabs::new(titles)
+        .select(selected)
+        .style(Style::default().fg(app.theme.text))
+        .highlight_style(
+            Style::default()
+                .fg(app.theme.info)
+                .add_modifier(Modifier::BOLD),
+        )
+        .divider(" | ");
+
+    frame.render_widget(tabs, area);
+}
+
+fn kind_color(kind: EventKind, theme: &Theme) -> Color {
+    match kind {
+        EventKind::Purchase => theme.text,
+        EventKind::Achievement => theme.highlight,
+        EventKind::Prestige => theme.special,
+        EventKind::Offline => theme.accent,
+        EventKind::Event => theme.affordable,
+    }
+}
+
+/// Helper function to create a centered rect
+fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let popup_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(r);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(popup_layout[1])[1]
+}
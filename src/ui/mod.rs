@@ -1,29 +1,41 @@
 mod achievements;
 pub mod animation;
 mod boss;
+mod compact;
 mod header;
 mod help;
 mod hint;
 mod layout;
+mod log;
 mod prestige;
 mod producers;
+mod queue;
+mod saves;
+#[cfg(test)]
+mod snapshot_tests;
 mod stats;
 mod upgrades;
 mod visualization;
 
 pub use achievements::filtered_achievement_count;
+pub use layout::determine_layout_mode;
 
 use ratatui::prelude::*;
 
 use crate::app::{App, LayoutMode, Panel};
+use crate::glyphs::glyphs;
+use crate::theme::Theme;
 
 pub fn render(frame: &mut Frame, app: &mut App) {
     // Check minimum terminal size
     let size = frame.area();
-    if size.width < 60 || size.height < 20 {
+    if size.width < compact::MIN_WIDTH || size.height < compact::MIN_HEIGHT {
         let msg = format!(
-            "Terminal too small!\nMinimum: 60x20\nCurrent: {}x{}",
-            size.width, size.height
+            "Too small!\n{}x{} min\nnow {}x{}",
+            compact::MIN_WIDTH,
+            compact::MIN_HEIGHT,
+            size.width,
+            size.height
         );
         let paragraph = ratatui::widgets::Paragraph::new(msg).alignment(Alignment::Center);
         frame.render_widget(paragraph, size);
@@ -32,26 +44,35 @@ pub fn render(frame: &mut Frame, app: &mut App) {
 
     // Boss mode
     if app.boss_mode {
-        boss::render(frame, size);
+        boss::render(frame, size, &app.theme);
         return;
     }
 
     // Offline report overlay
     if let Some(report) = &app.offline_report {
-        render_offline_report(frame, size, report);
+        render_offline_report(frame, size, report, &app.theme);
         return;
     }
 
     // Determine layout mode based on terminal width
-    app.layout_mode = layout::determine_layout_mode(size.width);
+    app.set_layout_mode(layout::determine_layout_mode(size));
 
-    // Normal UI
+    if app.layout_mode == LayoutMode::Compact {
+        compact::render(frame, size, app);
+    } else {
+        render_panels(frame, size, app);
+    }
+
+    render_overlays(frame, size, app);
+}
+
+fn render_panels(frame: &mut Frame, size: Rect, app: &mut App) {
     let chunks = layout::create_layout(size, app);
 
     header::render(frame, chunks.header, app);
 
     match app.layout_mode {
-        LayoutMode::Single => {
+        LayoutMode::Single | LayoutMode::Compact => {
             // Single panel mode: render active panel only
             let focused = true;
             match app.active_panel {
@@ -63,24 +84,36 @@ pub fn render(frame: &mut Frame, app: &mut App) {
                 }
             }
         }
-        LayoutMode::TwoColumn => {
-            // Two-column mode: Producers left, Visualization top-right, Upgrades bottom-right
+        LayoutMode::TwoColumn | LayoutMode::ThreeColumn => {
+            // Producers left, Visualization (or Stats) top-middle, Upgrades bottom-middle,
+            // and Stats in its own column when there is room for three
             let producers_focused = app.active_panel == Panel::Producers;
             let viz_focused = app.active_panel == Panel::Visualization;
             let upgrades_focused = app.active_panel == Panel::Upgrades;
+            let stats_focused = app.active_panel == Panel::Stats;
 
             producers::render(frame, chunks.left_panel, app, producers_focused);
 
             if let Some(right_top) = chunks.right_top {
-                visualization::render(frame, right_top, app, viz_focused);
+                if app.layout_mode == LayoutMode::TwoColumn && app.two_column_stats {
+                    stats::render(frame, right_top, app, stats_focused);
+                } else {
+                    visualization::render(frame, right_top, app, viz_focused);
+                }
             }
 
             if let Some(right_bottom) = chunks.right_bottom {
                 upgrades::render(frame, right_bottom, app, upgrades_focused);
             }
+
+            if let Some(third_column) = chunks.third_column {
+                stats::render(frame, third_column, app, stats_focused);
+            }
         }
     }
+}
 
+fn render_overlays(frame: &mut Frame, size: Rect, app: &mut App) {
     // Prestige panel overlay
     if app.show_prestige {
         prestige::render(frame, size, app);
@@ -91,6 +124,26 @@ pub fn render(frame: &mut Frame, app: &mut App) {
         achievements::render(frame, size, app);
     }
 
+    // Purchase queue overlay
+    if app.show_queue {
+        queue::render(frame, size, app);
+    }
+
+    // Event log overlay
+    if app.show_log {
+        log::render(frame, size, app);
+    }
+
+    // Save manager overlay
+    if app.show_saves {
+        saves::render(frame, size, app);
+    }
+
+    // Custom buy amount prompt
+    if let Some(input) = &app.buy_amount_input {
+        render_buy_amount_prompt(frame, size, input, &app.theme);
+    }
+
     // Achievement notification
     render_achievement_notification(frame, size, app);
 
@@ -101,12 +154,17 @@ pub fn render(frame: &mut Frame, app: &mut App) {
     help::render(frame, size, app);
 }
 
-fn render_offline_report(frame: &mut Frame, area: Rect, report: &crate::app::OfflineReport) {
+fn render_offline_report(
+    frame: &mut Frame,
+    area: Rect,
+    report: &crate::game::OfflineReport,
+    theme: &Theme,
+) {
     use ratatui::widgets::{Block, Borders, Clear, Paragraph};
 
     // Center the popup
-    let popup_width = 40;
-    let popup_height = 7;
+    let popup_width = 40.min(area.width);
+    let popup_height = 7.min(area.height);
     let x = (area.width.saturating_sub(popup_width)) / 2;
     let y = (area.height.saturating_sub(popup_height)) / 2;
     let popup_area = Rect::new(x, y, popup_width, popup_height);
@@ -124,7 +182,34 @@ fn render_offline_report(frame: &mut Frame, area: Rect, report: &crate::app::Off
     let block = Block::default()
         .title(" Offline Progress ")
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Yellow));
+        .border_set(glyphs().border)
+        .border_style(Style::default().fg(theme.highlight));
+
+    let paragraph = Paragraph::new(text)
+        .block(block)
+        .alignment(Alignment::Center);
+
+    frame.render_widget(paragraph, popup_area);
+}
+
+fn render_buy_amount_prompt(frame: &mut Frame, area: Rect, input: &str, theme: &Theme) {
+    use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+    let popup_width = 36.min(area.width);
+    let popup_height = 6.min(area.height);
+    let x = (area.width.saturating_sub(popup_width)) / 2;
+    let y = (area.height.saturating_sub(popup_height)) / 2;
+    let popup_area = Rect::new(x, y, popup_width, popup_height);
+
+    frame.render_widget(Clear, popup_area);
+
+    let text = format!("\nQuantity: {}_\n\nEnter to set, Esc to cancel", input);
+
+    let block = Block::default()
+        .title(" Custom Buy Amount ")
+        .borders(Borders::ALL)
+        .border_set(glyphs().border)
+        .border_style(Style::default().fg(theme.highlight));
 
     let paragraph = Paragraph::new(text)
         .block(block)
@@ -139,7 +224,7 @@ fn render_achievement_notification(frame: &mut Frame, area: Rect, app: &mut App)
     // Check for new achievements to display
     if let Some(achievement) = &app.achievement_notification {
         let popup_width = 50.min(area.width.saturating_sub(2));
-        let popup_height = 5;
+        let popup_height = 5.min(area.height);
         // Align to bottom right
         let x = area.width.saturating_sub(popup_width).saturating_sub(1);
         let y = area.height.saturating_sub(popup_height).saturating_sub(1);
@@ -147,19 +232,27 @@ fn render_achievement_notification(frame: &mut Frame, area: Rect, app: &mut App)
 
         frame.render_widget(Clear, popup_area);
 
-        let text = format!(
-            "\n Achievement Unlocked!\n {}: {}",
-            achievement.0, achievement.1
-        );
+        let text = if app.achievement_notification_count > 1 {
+            format!(
+                "\n +{} achievements! Latest: {}\n Press L to see them all",
+                app.achievement_notification_count, achievement.0
+            )
+        } else {
+            format!(
+                "\n Achievement Unlocked!\n {}: {}",
+                achievement.0, achievement.1
+            )
+        };
 
         let block = Block::default()
             .title(" Achievement ")
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::LightYellow));
+            .border_set(glyphs().border)
+            .border_style(Style::default().fg(app.theme.highlight));
 
         let paragraph = Paragraph::new(text)
             .block(block)
-            .style(Style::default().fg(Color::Yellow))
+            .style(Style::default().fg(app.theme.highlight))
             .alignment(Alignment::Center);
 
         frame.render_widget(paragraph, popup_area);
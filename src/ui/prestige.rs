@@ -4,6 +4,7 @@ use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragra
 use crate::app::App;
 use crate::format::format_energy;
 use crate::game::{PrestigeRequirement, PrestigeUpgrade};
+use crate::glyphs::glyphs;
 
 pub fn render(frame: &mut Frame, area: Rect, app: &App) {
     // Create a centered popup
@@ -15,8 +16,9 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App) {
     let block = Block::default()
         .title(" Stellar Ascension [A to close, Enter to buy/ascend] ")
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::LightCyan))
-        .style(Style::default().bg(Color::Black));
+        .border_set(glyphs().border)
+        .border_style(Style::default().fg(app.theme.accent))
+        .style(Style::default().bg(app.theme.background));
 
     let inner = block.inner(popup_area);
     frame.render_widget(block, popup_area);
@@ -69,9 +71,9 @@ fn render_prestige_info(frame: &mut Frame, area: Rect, app: &App) {
     );
 
     let style = if can_ascend {
-        Style::default().fg(Color::LightGreen)
+        Style::default().fg(app.theme.affordable)
     } else {
-        Style::default().fg(Color::White)
+        Style::default().fg(app.theme.text)
     };
 
     let paragraph = Paragraph::new(info_text).style(style);
@@ -101,7 +103,7 @@ fn render_prestige_upgrades(frame: &mut Frame, area: Rect, app: &App) {
             } else {
                 // Show what requirement is missing
                 let req_str = get_requirement_string(upgrade, app);
-                (format!("[REQ]"), req_str)
+                ("[REQ]".to_string(), req_str)
             };
 
             let cost_str = if is_purchased {
@@ -117,22 +119,22 @@ fn render_prestige_upgrades(frame: &mut Frame, area: Rect, app: &App) {
 
             let style = if i == app.selected_prestige_upgrade {
                 if is_purchased {
-                    Style::default().fg(Color::Black).bg(Color::DarkGray)
+                    app.theme.selected(app.theme.muted)
                 } else if is_available && can_afford {
-                    Style::default().fg(Color::Black).bg(Color::Green)
+                    app.theme.selected(app.theme.affordable)
                 } else if is_available {
-                    Style::default().fg(Color::Black).bg(Color::Red)
+                    app.theme.selected(app.theme.unaffordable)
                 } else {
-                    Style::default().fg(Color::Black).bg(Color::DarkGray)
+                    app.theme.selected(app.theme.muted)
                 }
             } else if is_purchased {
-                Style::default().fg(Color::DarkGray)
+                Style::default().fg(app.theme.muted)
             } else if is_available && can_afford {
-                Style::default().fg(Color::Green)
+                Style::default().fg(app.theme.affordable)
             } else if is_available {
-                Style::default().fg(Color::Yellow)
+                Style::default().fg(app.theme.highlight)
             } else {
-                Style::default().fg(Color::DarkGray)
+                Style::default().fg(app.theme.muted)
             };
 
             ListItem::new(line).style(style)
@@ -151,7 +153,7 @@ fn render_prestige_upgrades(frame: &mut Frame, area: Rect, app: &App) {
 
     let header_widget = Paragraph::new(header).style(
         Style::default()
-            .fg(Color::Cyan)
+            .fg(app.theme.accent)
             .add_modifier(Modifier::BOLD),
     );
     frame.render_widget(header_widget, chunks[0]);
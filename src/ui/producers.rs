@@ -1,29 +1,30 @@
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph};
 
-use crate::app::App;
+use crate::app::{App, BuyAmount};
 use crate::format::{format_cost, format_duration, format_energy, format_rate};
-use crate::game::{calculate_bulk_cost, Producer};
+use crate::game::{
+    calculate_bulk_cost, calculate_sell_refund, signature_upgrade_id, Producer, Upgrade,
+};
+use crate::glyphs::{glyphs, icon};
 
 const INDICATOR_HEIGHT: u16 = 8;
 
 pub fn render(frame: &mut Frame, area: Rect, app: &App, focused: bool) {
     let visible = app.game.visible_producers();
 
-    let border_color = if focused {
-        Color::Yellow
-    } else {
-        Color::DarkGray
-    };
+    let border_color = app.theme.border(focused);
+    let mode = if app.sell_mode { "Sell" } else { "Buy" };
     let title = if focused {
-        format!(" Producers [Buy: {}] *", app.buy_amount.label())
+        format!(" Producers [{}: {}] *", mode, app.buy_amount.label())
     } else {
-        format!(" Producers [Buy: {}] ", app.buy_amount.label())
+        format!(" Producers [{}: {}] ", mode, app.buy_amount.label())
     };
 
     let block = Block::default()
         .title(title)
         .borders(Borders::ALL)
+        .border_set(glyphs().border)
         .border_style(Style::default().fg(border_color));
 
     let inner = block.inner(area);
@@ -83,16 +84,13 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App, focused: bool) {
         .max("Rate".len())
         + 1;
 
+    let cost_header = if app.sell_mode { "Refund" } else { "Cost" };
     let cost_width = visible
         .iter()
-        .map(|(_, p)| {
-            let owned = app.game.producer_count(p.id);
-            let qty = app.get_display_quantity_for_producer(p);
-            format_cost(calculate_bulk_cost(p.base_cost, owned, qty, p.id)).len()
-        })
+        .map(|(_, p)| format_cost(display_price(app, p)).len())
         .max()
         .unwrap_or(0)
-        .max("Cost".len())
+        .max(cost_header.len())
         + 1;
 
     // Render header
@@ -101,7 +99,7 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App, focused: bool) {
         "Producer",
         "Own",
         "Rate",
-        "Cost",
+        cost_header,
         name_width = name_width,
         owned_width = owned_width,
         rate_width = rate_width,
@@ -109,7 +107,7 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App, focused: bool) {
     );
     let header_widget = Paragraph::new(header).style(
         Style::default()
-            .fg(Color::Cyan)
+            .fg(app.theme.accent)
             .add_modifier(Modifier::BOLD),
     );
     frame.render_widget(header_widget, chunks[0]);
@@ -132,10 +130,23 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App, focused: bool) {
     }
 }
 
+/// Price shown in the cost column: bulk cost when buying, refund when selling
+pub(super) fn display_price(app: &App, producer: &Producer) -> f64 {
+    let owned = app.game.producer_count(producer.id);
+    if app.sell_mode {
+        let qty = app.calculate_sell_quantity(producer);
+        calculate_sell_refund(producer.base_cost, owned, qty, producer.id)
+    } else {
+        let qty = app.get_display_quantity_for_producer(producer);
+        calculate_bulk_cost(producer.base_cost, owned, qty, producer.id)
+    }
+}
+
 fn should_show_indicator(app: &App) -> bool {
     app.show_producer_detail && app.game.total_producers_owned() > 0
 }
 
+#[allow(clippy::too_many_arguments)]
 fn render_producer_list(
     frame: &mut Frame,
     area: Rect,
@@ -151,17 +162,27 @@ fn render_producer_list(
         .enumerate()
         .map(|(display_idx, (_, producer))| {
             let owned = app.game.producer_count(producer.id);
-            let quantity = app.get_buy_quantity_for_producer(producer);
-            let display_quantity = app.get_display_quantity_for_producer(producer);
-            let cost =
-                calculate_bulk_cost(producer.base_cost, owned, display_quantity, producer.id);
-            let can_afford = app.game.energy >= cost && quantity > 0;
+            let quantity = if app.sell_mode {
+                app.calculate_sell_quantity(producer)
+            } else {
+                app.get_buy_quantity_for_producer(producer)
+            };
+            let cost = display_price(app, producer);
+            // In sell mode, "affordable" means enough units are owned to sell
+            let can_afford = quantity > 0 && (app.sell_mode || app.game.energy >= cost);
 
             let effective_rate = producer.base_energy_per_second
                 * app.game.get_producer_multiplier(producer.id)
                 * app.game.get_global_multiplier();
 
-            let buy_label = if quantity == 0 {
+            let milestone = if !app.sell_mode && app.buy_amount == BuyAmount::Milestone {
+                app.game.next_producer_milestone(producer.id)
+            } else {
+                None
+            };
+            let buy_label = if let Some(target) = milestone {
+                format!("({}{})", glyphs().arrow, target)
+            } else if quantity == 0 {
                 format!("({})", app.buy_amount.label())
             } else if quantity == 1 {
                 String::new()
@@ -171,7 +192,7 @@ fn render_producer_list(
 
             let line = format!(
                 "{} {:<name_width$} {:>owned_width$}  {:>rate_width$}  {:>cost_width$} {}",
-                producer.icon,
+                icon(producer),
                 producer.name,
                 owned,
                 format_rate(effective_rate),
@@ -183,16 +204,21 @@ fn render_producer_list(
                 cost_width = cost_width
             );
 
+            let action_color = if app.sell_mode {
+                app.theme.selling
+            } else {
+                app.theme.affordable
+            };
             let style = if display_idx == app.selected_producer {
                 if can_afford {
-                    Style::default().fg(Color::Black).bg(Color::Green)
+                    app.theme.selected(action_color)
                 } else {
-                    Style::default().fg(Color::Black).bg(Color::Red)
+                    app.theme.selected(app.theme.unaffordable)
                 }
             } else if can_afford {
-                Style::default().fg(Color::Green)
+                Style::default().fg(action_color)
             } else {
-                Style::default().fg(Color::DarkGray)
+                Style::default().fg(app.theme.muted)
             };
 
             ListItem::new(line).style(style)
@@ -238,9 +264,10 @@ fn render_producer_indicator(
 
     // Create the indicator block
     let indicator_block = Block::default()
-        .title(format!(" {} {} ", producer.icon, producer.name))
+        .title(format!(" {} {} ", icon(producer), producer.name))
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Cyan));
+        .border_set(glyphs().border)
+        .border_style(Style::default().fg(app.theme.accent));
 
     let indicator_inner = indicator_block.inner(area);
     frame.render_widget(indicator_block, area);
@@ -283,45 +310,94 @@ fn render_producer_indicator(
         .split(indicator_inner);
 
     // Left column stats
-    let left_text = vec![
+    let mut left_text = vec![
         Line::from(vec![
-            Span::styled("Owned:     ", Style::default().fg(Color::DarkGray)),
-            Span::styled(format!("{}", owned), Style::default().fg(Color::White)),
+            Span::styled("Owned:     ", Style::default().fg(app.theme.muted)),
+            Span::styled(format!("{}", owned), Style::default().fg(app.theme.text)),
         ]),
         Line::from(vec![
-            Span::styled("Total Rate: ", Style::default().fg(Color::DarkGray)),
-            Span::styled(format_rate(total_rate), Style::default().fg(Color::Green)),
+            Span::styled("Total Rate: ", Style::default().fg(app.theme.muted)),
+            Span::styled(
+                format_rate(total_rate),
+                Style::default().fg(app.theme.affordable),
+            ),
         ]),
         Line::from(vec![
-            Span::styled("Lifetime:  ", Style::default().fg(Color::DarkGray)),
+            Span::styled("Lifetime:  ", Style::default().fg(app.theme.muted)),
             Span::styled(
                 format!("{} E", format_energy(lifetime)),
-                Style::default().fg(Color::Yellow),
+                Style::default().fg(app.theme.highlight),
             ),
         ]),
+        Line::from(vec![
+            Span::styled("Milestone: ", Style::default().fg(app.theme.muted)),
+            match app.game.next_producer_milestone(producer.id) {
+                Some(target) => Span::styled(
+                    format!(
+                        "{} ({})",
+                        target,
+                        format_cost(calculate_bulk_cost(
+                            producer.base_cost,
+                            owned,
+                            target - owned,
+                            producer.id
+                        ))
+                    ),
+                    Style::default().fg(app.theme.info),
+                ),
+                None => Span::styled("All reached", Style::default().fg(app.theme.muted)),
+            },
+        ]),
     ];
+    if let Some(line) = signature_line(app, producer) {
+        left_text.push(line);
+    }
 
     // Right column stats
     let right_text = vec![
         Line::from(vec![
-            Span::styled("Share:     ", Style::default().fg(Color::DarkGray)),
-            Span::styled(format!("{:.1}%", share), Style::default().fg(Color::Cyan)),
+            Span::styled("Share:     ", Style::default().fg(app.theme.muted)),
+            Span::styled(
+                format!("{:.1}%", share),
+                Style::default().fg(app.theme.accent),
+            ),
         ]),
         Line::from(vec![
-            Span::styled("Next Cost: ", Style::default().fg(Color::DarkGray)),
-            Span::styled(format_cost(next_cost), Style::default().fg(Color::White)),
+            if app.sell_mode {
+                Span::styled("Refund:    ", Style::default().fg(app.theme.muted))
+            } else {
+                Span::styled("Next Cost: ", Style::default().fg(app.theme.muted))
+            },
+            Span::styled(
+                format_cost(if app.sell_mode {
+                    display_price(app, producer)
+                } else {
+                    next_cost
+                }),
+                Style::default().fg(app.theme.text),
+            ),
         ]),
         Line::from(vec![
-            Span::styled("ROI:       ", Style::default().fg(Color::DarkGray)),
+            Span::styled("ROI:       ", Style::default().fg(app.theme.muted)),
             Span::styled(
                 if roi_seconds > 0 {
                     format_duration(roi_seconds)
                 } else {
                     "N/A".to_string()
                 },
-                Style::default().fg(Color::Magenta),
+                Style::default().fg(app.theme.special),
             ),
         ]),
+        Line::from(vec![
+            Span::styled("Next Bonus: ", Style::default().fg(app.theme.muted)),
+            match app.game.next_count_bonus(producer.id) {
+                Some(bonus) => Span::styled(
+                    format!("at {} ({})", bonus.count, bonus.effect.label()),
+                    Style::default().fg(app.theme.affordable),
+                ),
+                None => Span::styled("All reached", Style::default().fg(app.theme.muted)),
+            },
+        ]),
     ];
 
     let left_paragraph = Paragraph::new(left_text);
@@ -330,3 +406,28 @@ fn render_producer_indicator(
     frame.render_widget(left_paragraph, columns[0]);
     frame.render_widget(right_paragraph, columns[1]);
 }
+
+/// Signature mechanic name and its current bonus (producers 3-20 only)
+fn signature_line(app: &App, producer: &Producer) -> Option<Line<'static>> {
+    let signature_id = signature_upgrade_id(producer.id, 1);
+    let upgrade = Upgrade::by_id(signature_id)?;
+
+    let value = if app.game.has_signature(producer.id) {
+        let bonus = app.game.signature_bonus(producer.id);
+        let target = if producer.id == 14 { " all" } else { "" };
+        Span::styled(
+            format!("{} +{:.0}%{}", upgrade.name, bonus * 100.0, target),
+            Style::default().fg(app.theme.special),
+        )
+    } else {
+        Span::styled(
+            format!("{} (locked)", upgrade.name),
+            Style::default().fg(app.theme.muted),
+        )
+    };
+
+    Some(Line::from(vec![
+        Span::styled("Signature: ", Style::default().fg(app.theme.muted)),
+        value,
+    ]))
+}
@@ -0,0 +1,135 @@
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph};
+
+use crate::app::App;
+use crate::format::{format_cost, format_duration};
+use crate::game::{QueuedPurchase, MAX_QUEUE_LEN};
+use crate::glyphs::glyphs;
+
+pub fn render(frame: &mut Frame, area: Rect, app: &App) {
+    // Create a centered popup
+    let popup_area = centered_rect(70, 70, area);
+
+    // Clear the background
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(" Purchase Queue [J/K to reorder, D to remove, W to close] ")
+        .borders(Borders::ALL)
+        .border_set(glyphs().border)
+        .border_style(Style::default().fg(app.theme.affordable))
+        .style(Style::default().bg(app.theme.background));
+
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2), // Summary
+            Constraint::Length(1), // Header
+            Constraint::Min(1),    // Queue list
+        ])
+        .split(inner);
+
+    let queue = &app.game.purchase_queue;
+    let summary = format!(
+        "  {}/{} queued  |  Press E on a producer or upgrade to add it",
+        queue.len(),
+        MAX_QUEUE_LEN
+    );
+    frame.render_widget(
+        Paragraph::new(summary).style(Style::default().fg(app.theme.accent)),
+        chunks[0],
+    );
+
+    if queue.is_empty() {
+        let paragraph =
+            Paragraph::new("\n  The queue is empty.").style(Style::default().fg(app.theme.muted));
+        frame.render_widget(paragraph, chunks[2]);
+        return;
+    }
+
+    let header = format!(
+        " {:>3}  {:<30} {:>6}  {:>14}  {}",
+        "#", "Item", "Qty", "Cost", "Status"
+    );
+    let header_widget = Paragraph::new(header).style(
+        Style::default()
+            .fg(app.theme.accent)
+            .add_modifier(Modifier::BOLD),
+    );
+    frame.render_widget(header_widget, chunks[1]);
+
+    let eps = app.game.total_energy_per_second();
+
+    let items: Vec<ListItem> = queue
+        .iter()
+        .enumerate()
+        .map(|(i, item)| {
+            let cost = app.game.queued_purchase_cost(item);
+            let qty = match item {
+                QueuedPurchase::Producer { quantity, .. } => format!("x{}", quantity),
+                QueuedPurchase::Upgrade { .. } => "-".to_string(),
+            };
+
+            let status = if i > 0 {
+                "Waiting".to_string()
+            } else if app.game.energy >= cost {
+                "Next tick".to_string()
+            } else if eps > 0.0 {
+                let secs = ((cost - app.game.energy) / eps).ceil() as u64;
+                format!("in ~{}", format_duration(secs))
+            } else {
+                "Saving".to_string()
+            };
+
+            let line = format!(
+                " {:>3}  {:<30} {:>6}  {:>14}  {}",
+                i + 1,
+                item.name(),
+                qty,
+                format_cost(cost),
+                status
+            );
+
+            let style = if i == app.selected_queue_item {
+                app.theme.selected(app.theme.affordable)
+            } else if i == 0 {
+                Style::default().fg(app.theme.highlight)
+            } else {
+                Style::default().fg(app.theme.text)
+            };
+
+            ListItem::new(line).style(style)
+        })
+        .collect();
+
+    let list = List::new(items);
+    let mut state = ListState::default();
+    state.select(Some(
+        app.selected_queue_item.min(queue.len().saturating_sub(1)),
+    ));
+    frame.render_stateful_widget(list, chunks[2], &mut state);
+}
+
+/// Helper function to create a centered rect
+fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let popup_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(r);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(popup_layout[1])[1]
+}
@@ -0,0 +1,172 @@
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph};
+
+use crate::app::{App, SavePrompt};
+use crate::format::{format_duration, format_energy, format_rate, format_time_ago};
+use crate::glyphs::glyphs;
+
+pub fn render(frame: &mut Frame, area: Rect, app: &App) {
+    // Create a centered popup
+    let popup_area = centered_rect(80, 70, area);
+
+    // Clear the background
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(" Save Slots [Enter switch, c copy, r rename, D delete, o close] ")
+        .borders(Borders::ALL)
+        .border_set(glyphs().border)
+        .border_style(Style::default().fg(app.theme.accent))
+        .style(Style::default().bg(app.theme.background));
+
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2), // Summary
+            Constraint::Length(1), // Header
+            Constraint::Min(1),    // Slot list
+            Constraint::Length(2), // Prompt or status
+        ])
+        .split(inner);
+
+    let summary = format!(
+        "  {} slot{}  |  Playing '{}'",
+        app.save_slots.len(),
+        if app.save_slots.len() == 1 { "" } else { "s" },
+        app.save_label
+    );
+    frame.render_widget(
+        Paragraph::new(summary).style(Style::default().fg(app.theme.accent)),
+        chunks[0],
+    );
+
+    let label_width = app
+        .save_slots
+        .iter()
+        .map(|slot| slot.label.len())
+        .max()
+        .unwrap_or(0)
+        .max("Save".len());
+
+    let header = format!(
+        "   {:<label_width$}  {:<14}  {:>24}  {:>26}  {:>4}  {:>6}  {:>9}",
+        "Save", "Last saved", "Energy", "E/s", "Asc", "Chips", "Played"
+    );
+    let header_widget = Paragraph::new(header).style(
+        Style::default()
+            .fg(app.theme.accent)
+            .add_modifier(Modifier::BOLD),
+    );
+    frame.render_widget(header_widget, chunks[1]);
+
+    let items: Vec<ListItem> = app
+        .save_slots
+        .iter()
+        .enumerate()
+        .map(|(i, slot)| {
+            let playing = slot.label == app.save_label;
+            let marker = if playing { ">" } else { " " };
+            let line = match &slot.summary {
+                Some(s) => format!(
+                    " {} {:<label_width$}  {:<14}  {:>24}  {:>26}  {:>4}  {:>6}  {:>9}",
+                    marker,
+                    slot.label,
+                    if playing {
+                        "now".to_string()
+                    } else {
+                        format_time_ago(s.last_save)
+                    },
+                    format_energy(s.energy),
+                    format_rate(s.energy_per_second),
+                    s.ascensions,
+                    s.stellar_chips,
+                    format_duration(s.play_time_secs)
+                ),
+                None => format!(" {} {:<label_width$}  (unreadable)", marker, slot.label),
+            };
+
+            let style = if i == app.selected_save {
+                app.theme.selected(app.theme.accent)
+            } else if playing {
+                Style::default().fg(app.theme.highlight)
+            } else if slot.summary.is_none() {
+                Style::default().fg(app.theme.muted)
+            } else {
+                Style::default().fg(app.theme.text)
+            };
+
+            ListItem::new(line).style(style)
+        })
+        .collect();
+
+    let list = List::new(items);
+    let mut state = ListState::default();
+    state.select(Some(app.selected_save));
+    frame.render_stateful_widget(list, chunks[2], &mut state);
+
+    render_footer(frame, chunks[3], app);
+}
+
+/// Bottom line: the pending prompt, otherwise the last action's result
+fn render_footer(frame: &mut Frame, area: Rect, app: &App) {
+    let selected = app
+        .save_slots
+        .get(app.selected_save)
+        .map(|slot| slot.label.as_str())
+        .unwrap_or("");
+
+    let (text, color) = match &app.save_prompt {
+        Some(SavePrompt::Duplicate(input)) => (
+            format!(
+                "  Copy '{}' as: {}_   (Enter to confirm, Esc to cancel)",
+                selected, input
+            ),
+            app.theme.highlight,
+        ),
+        Some(SavePrompt::Rename(input)) => (
+            format!(
+                "  Rename '{}' to: {}_   (Enter to confirm, Esc to cancel)",
+                selected, input
+            ),
+            app.theme.highlight,
+        ),
+        Some(SavePrompt::ConfirmDelete) => (
+            format!("  Delete '{}' for good? (y/n)", selected),
+            app.theme.unaffordable,
+        ),
+        None => match &app.save_status {
+            Some(status) => (format!("  {}", status), app.theme.info),
+            None => (
+                "  Switching saves the current slot first".to_string(),
+                app.theme.muted,
+            ),
+        },
+    };
+
+    let paragraph = Paragraph::new(format!("\n{}", text)).style(Style::default().fg(color));
+    frame.render_widget(paragraph, area);
+}
+
+/// Helper function to create a centered rect
+fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let popup_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(r);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(popup_layout[1])[1]
+}
@@ -0,0 +1,154 @@
+//! Renders fixture saves into a `TestBackend` at each layout's size and
+//! compares the screen text with the snapshots in `snapshots/`.
+//!
+//! After an intended UI change, regenerate them with
+//! `INSTA_UPDATE=always cargo test snapshot_tests` (or `cargo insta review`
+//! to step through the differences) and commit the `.snap` files.
+
+use ratatui::backend::TestBackend;
+use ratatui::Terminal;
+
+use crate::app::{App, LayoutMode, Panel, SaveData};
+use crate::game::OfflineReport;
+
+/// Compact, single-, two- and three-column layouts
+const SIZES: [(u16, u16); 4] = [(56, 18), (100, 36), (150, 42), (200, 50)];
+
+/// A small tmux pane, narrower than most popups
+const SMALL: (u16, u16) = (30, 10);
+
+/// An app showing one of the saves in `fixtures/`
+fn fixture(name: &str) -> App {
+    let json = match name {
+        "early" => include_str!("fixtures/early.json"),
+        "mid" | "auto" => include_str!("fixtures/mid.json"),
+        "ascended" => include_str!("fixtures/ascended.json"),
+        _ => panic!("no fixture named {}", name),
+    };
+    let save: SaveData = serde_json::from_str(json).unwrap();
+    let mut app = App::new(name.to_string());
+    app.game = save.game_state;
+    if name == "auto" {
+        app.auto_mode = true;
+        app.auto_speed = 4.0;
+    }
+    app
+}
+
+/// The screen as text, one line per row with trailing blanks trimmed
+fn render(app: &mut App, (width, height): (u16, u16)) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal.draw(|f| super::render(f, app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    (0..height)
+        .map(|y| {
+            let row: String = (0..width).map(|x| buffer[(x, y)].symbol()).collect();
+            row.trim_end().to_string()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn assert_screen(name: &str, app: &mut App, size: (u16, u16)) {
+    let screen = render(app, size);
+    let mut settings = insta::Settings::clone_current();
+    settings.set_prepend_module_to_snapshot(false);
+    settings.set_description(format!("{}x{}", size.0, size.1));
+    settings.bind(|| insta::assert_snapshot!(format!("{}_{}x{}", name, size.0, size.1), screen));
+}
+
+#[test]
+fn test_main_screen() {
+    for name in ["early", "mid", "ascended", "auto"] {
+        for size in SIZES {
+            assert_screen(name, &mut fixture(name), size);
+        }
+    }
+}
+
+#[test]
+fn test_upgrades_tooltip() {
+    for size in [SIZES[1], SIZES[2]] {
+        let mut app = fixture("mid");
+        app.focus_panel(Panel::Upgrades);
+        app.show_upgrade_tooltip = true;
+        assert_screen("upgrades_tooltip", &mut app, size);
+    }
+}
+
+#[test]
+fn test_stats_and_visualization_panels() {
+    // Single-column mode shows one of them in place of the producers when asked
+    let size = SIZES[1];
+    let mut app = fixture("mid");
+    app.set_layout_mode(LayoutMode::Single);
+    app.show_stats();
+    assert_screen("stats", &mut app, size);
+
+    let mut app = fixture("mid");
+    app.set_layout_mode(LayoutMode::Single);
+    app.show_visualization();
+    assert_screen("visualization", &mut app, size);
+}
+
+/// An overlay over a fixture, at compact and two-column sizes
+fn assert_overlay(overlay: &str, name: &str, open: impl Fn(&mut App)) {
+    for size in [SIZES[0], SIZES[2]] {
+        let mut app = fixture(name);
+        open(&mut app);
+        assert_screen(overlay, &mut app, size);
+    }
+}
+
+#[test]
+fn test_overlays() {
+    assert_overlay("prestige", "ascended", App::toggle_prestige);
+    assert_overlay("achievements", "mid", App::toggle_achievements);
+    assert_overlay("help", "early", App::toggle_help);
+    assert_overlay("hint", "early", App::show_hint);
+    assert_overlay("offline_report", "mid", open_offline_report);
+}
+
+fn open_offline_report(app: &mut App) {
+    app.offline_report = Some(OfflineReport {
+        duration_secs: 2 * 60 * 60 + 15 * 60,
+        energy_earned: 1.25e6,
+    })
+}
+
+fn open_buy_amount_prompt(app: &mut App) {
+    app.start_custom_buy_amount();
+    app.push_buy_amount_digit('2');
+    app.push_buy_amount_digit('5');
+}
+
+#[test]
+fn test_popups_in_small_terminals() {
+    // Fixed-size popups are clamped to the screen instead of drawing past it
+    let mut app = fixture("mid");
+    open_offline_report(&mut app);
+    assert_screen("offline_report", &mut app, SMALL);
+    let mut app = fixture("mid");
+    open_buy_amount_prompt(&mut app);
+    assert_screen("buy_amount_prompt", &mut app, SMALL);
+
+    // Every overlay draws without panicking down to the compact minimum
+    let overlays: [fn(&mut App); 8] = [
+        open_offline_report,
+        open_buy_amount_prompt,
+        App::toggle_prestige,
+        App::toggle_achievements,
+        App::toggle_help,
+        App::show_hint,
+        App::toggle_queue,
+        |app| app.achievement_notification = Some(("Clicker".into(), "Mine once".into())),
+    ];
+    let minimum = (super::compact::MIN_WIDTH, super::compact::MIN_HEIGHT);
+    for open in overlays {
+        for size in [minimum, (24, 6), SMALL] {
+            let mut app = fixture("ascended");
+            open(&mut app);
+            render(&mut app, size);
+        }
+    }
+}
@@ -4,6 +4,7 @@ use ratatui::widgets::{Block, Borders, Paragraph};
 use crate::app::App;
 use crate::format::{format_duration, format_energy, format_rate};
 use crate::game::{Achievement, Producer};
+use crate::glyphs::{glyphs, icon};
 
 pub fn render(frame: &mut Frame, area: Rect, app: &App, focused: bool) {
     let total_earned = format_energy(app.game.total_energy_earned);
@@ -18,7 +19,7 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App, focused: bool) {
             let count = app.game.producer_count(p.id);
             let mult = app.game.get_producer_multiplier(p.id) * app.game.get_global_multiplier();
             let production = p.base_energy_per_second * count as f64 * mult;
-            (p.icon, p.name, production)
+            (icon(p), p.name, production)
         })
         .filter(|(_, _, production)| *production > 0.0)
         .max_by(|(_, _, a), (_, _, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
@@ -111,11 +112,7 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App, focused: bool) {
         prestige_upgrades
     );
 
-    let border_color = if focused {
-        Color::Blue
-    } else {
-        Color::DarkGray
-    };
+    let border_color = app.theme.border(focused);
     let title = if focused {
         " Statistics *"
     } else {
@@ -125,11 +122,12 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App, focused: bool) {
     let block = Block::default()
         .title(title)
         .borders(Borders::ALL)
+        .border_set(glyphs().border)
         .border_style(Style::default().fg(border_color));
 
     let paragraph = Paragraph::new(text)
         .block(block)
-        .style(Style::default().fg(Color::White));
+        .style(Style::default().fg(app.theme.text));
 
     frame.render_widget(paragraph, area);
 }
@@ -3,27 +3,26 @@ use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragra
 
 use crate::app::App;
 use crate::format::format_cost;
+use crate::glyphs::glyphs;
+use crate::theme::Theme;
 
 pub fn render(frame: &mut Frame, area: Rect, app: &App, focused: bool) {
     let available = app.game.available_upgrades();
 
-    let border_color = if focused {
-        Color::Magenta
-    } else {
-        Color::DarkGray
-    };
+    let border_color = app.theme.border(focused);
     let title = if focused { " Upgrades *" } else { " Upgrades " };
 
     let block = Block::default()
         .title(title)
         .borders(Borders::ALL)
+        .border_set(glyphs().border)
         .border_style(Style::default().fg(border_color));
 
     if available.is_empty() {
         let text = "\n  No upgrades available yet.\n\n  Build more producers to unlock upgrades!";
         let paragraph = Paragraph::new(text)
             .block(block)
-            .style(Style::default().fg(Color::DarkGray));
+            .style(Style::default().fg(app.theme.muted));
         frame.render_widget(paragraph, area);
         return;
     }
@@ -70,14 +69,14 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App, focused: bool) {
 
             let style = if i == app.selected_upgrade {
                 if can_afford {
-                    Style::default().fg(Color::Black).bg(Color::Green)
+                    app.theme.selected(app.theme.affordable)
                 } else {
-                    Style::default().fg(Color::Black).bg(Color::Red)
+                    app.theme.selected(app.theme.unaffordable)
                 }
             } else if can_afford {
-                Style::default().fg(Color::Green)
+                Style::default().fg(app.theme.affordable)
             } else {
-                Style::default().fg(Color::DarkGray)
+                Style::default().fg(app.theme.muted)
             };
 
             ListItem::new(line).style(style)
@@ -103,7 +102,7 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App, focused: bool) {
     );
     let header_widget = Paragraph::new(header).style(
         Style::default()
-            .fg(Color::Cyan)
+            .fg(app.theme.accent)
             .add_modifier(Modifier::BOLD),
     );
     frame.render_widget(header_widget, chunks[0]);
@@ -118,11 +117,16 @@ pub fn render(frame: &mut Frame, area: Rect, app: &App, focused: bool) {
 
     // Render tooltip if hover timer has reached threshold
     if app.show_upgrade_tooltip && app.selected_upgrade < available.len() {
-        render_tooltip(frame, area, &available[app.selected_upgrade]);
+        render_tooltip(frame, area, available[app.selected_upgrade], &app.theme);
     }
 }
 
-fn render_tooltip(frame: &mut Frame, parent_area: Rect, upgrade: &crate::game::Upgrade) {
+fn render_tooltip(
+    frame: &mut Frame,
+    parent_area: Rect,
+    upgrade: &crate::game::Upgrade,
+    theme: &Theme,
+) {
     // Calculate tooltip dimensions
     let tooltip_height = 4;
     let tooltip_width = parent_area.width.min(60);
@@ -143,12 +147,13 @@ fn render_tooltip(frame: &mut Frame, parent_area: Rect, upgrade: &crate::game::U
     let block = Block::default()
         .title(format!(" {} ", upgrade.name))
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Cyan));
+        .border_set(glyphs().border)
+        .border_style(Style::default().fg(theme.accent));
 
     let text = upgrade.description.to_string();
     let paragraph = Paragraph::new(text)
         .block(block)
-        .style(Style::default().fg(Color::White))
+        .style(Style::default().fg(theme.text))
         .wrap(ratatui::widgets::Wrap { trim: true });
 
     frame.render_widget(paragraph, tooltip_area);
@@ -4,6 +4,8 @@ use ratatui::widgets::{Block, Borders, Widget};
 use super::animation::{orbit, OrbitalBody, StarField};
 use crate::app::App;
 use crate::game::Producer;
+use crate::glyphs::{glyphs, icon};
+use crate::theme::Theme;
 
 /// A cell in the render buffer
 #[derive(Clone, Copy)]
@@ -16,28 +18,11 @@ impl Default for Cell {
     fn default() -> Self {
         Self {
             ch: ' ',
-            fg: Color::White,
+            fg: Color::Reset,
         }
     }
 }
 
-/// Get color for a producer based on its tier
-fn producer_color(tier: usize) -> Color {
-    match tier {
-        0 => Color::Rgb(200, 200, 100), // Solar Panel - pale yellow
-        1 => Color::Rgb(150, 150, 150), // Mining Drone - gray
-        2 => Color::Rgb(255, 140, 0),   // Asteroid Mine - orange
-        3 => Color::Rgb(100, 200, 255), // Orbital Station - light blue
-        4 => Color::Rgb(200, 200, 220), // Lunar Colony - silver
-        5 => Color::Rgb(100, 255, 100), // Planetary Harvester - green
-        6 => Color::Rgb(255, 100, 255), // Fusion Reactor - magenta
-        7 => Color::Rgb(255, 215, 0),   // Dyson Swarm - gold
-        8 => Color::Rgb(255, 255, 100), // Dyson Sphere - bright yellow
-        9 => Color::Rgb(255, 255, 255), // Star Forge - white
-        _ => Color::White,
-    }
-}
-
 /// Buffer for layered rendering
 struct RenderBuffer {
     cells: Vec<Cell>,
@@ -72,16 +57,13 @@ impl RenderBuffer {
 }
 
 pub fn render(frame: &mut Frame, area: Rect, app: &mut App, focused: bool) {
-    let border_color = if focused {
-        Color::Cyan
-    } else {
-        Color::DarkGray
-    };
+    let border_color = app.theme.border(focused);
     let title = if focused { " System *" } else { " System " };
 
     let block = Block::default()
         .title(title)
         .borders(Borders::ALL)
+        .border_set(glyphs().border)
         .border_style(Style::default().fg(border_color));
 
     let inner = block.inner(area);
@@ -123,7 +105,7 @@ pub fn render(frame: &mut Frame, area: Rect, app: &mut App, focused: bool) {
             let initial_angle = (producer.id as f64) * 0.7; // Spread out initial positions
             orbital_bodies.push(OrbitalBody {
                 producer_id: producer.id,
-                icon: producer.icon,
+                icon: icon(producer),
                 tier,
                 initial_angle,
             });
@@ -131,13 +113,18 @@ pub fn render(frame: &mut Frame, area: Rect, app: &mut App, focused: bool) {
     }
 
     // Layer 1: Stars background
-    render_stars(&mut buffer, &app.animation.stars, app.animation.frame_count);
+    render_stars(
+        &mut buffer,
+        &app.animation.stars,
+        app.animation.frame_count,
+        &app.theme,
+    );
 
     // Layer 2: Orbit paths (subtle dotted circles)
-    render_orbit_paths(&mut buffer, center_x, center_y, &owned_tiers);
+    render_orbit_paths(&mut buffer, center_x, center_y, &owned_tiers, &app.theme);
 
     // Layer 3: Central sun
-    render_sun(&mut buffer, center_x, center_y);
+    render_sun(&mut buffer, center_x, center_y, &app.theme);
 
     // Layer 4: Orbiting producer icons
     render_producers(
@@ -146,6 +133,7 @@ pub fn render(frame: &mut Frame, area: Rect, app: &mut App, focused: bool) {
         center_y,
         app.animation.frame_count,
         &orbital_bodies,
+        &app.theme,
     );
 
     // Render buffer to frame (only the viz area, not the gauge space)
@@ -169,18 +157,18 @@ pub fn render(frame: &mut Frame, area: Rect, app: &mut App, focused: bool) {
     }
 }
 
-fn render_stars(buffer: &mut RenderBuffer, stars: &StarField, frame_count: u64) {
+fn render_stars(buffer: &mut RenderBuffer, stars: &StarField, frame_count: u64, theme: &Theme) {
     for star in &stars.stars {
         if star.x < buffer.width && star.y < buffer.height {
             let ch = StarField::star_char(star.kind, star.phase, frame_count);
             if ch != ' ' {
                 // Brightness based on character (brighter = more intense stars)
                 let brightness = match ch {
-                    '✽' | '✻' => Color::White,
-                    '✳' | '✢' => Color::Gray,
-                    '∗' | '✧' => Color::Rgb(180, 180, 180),
-                    '·' => Color::DarkGray,
-                    _ => Color::DarkGray,
+                    '✽' | '✻' | '*' => theme.stars[0],
+                    '✳' | '✢' | '+' => theme.stars[1],
+                    '∗' | '✧' => theme.stars[2],
+                    '·' | '.' => theme.stars[3],
+                    _ => theme.stars[3],
                 };
                 buffer.set(star.x, star.y, ch, brightness);
             }
@@ -188,9 +176,9 @@ fn render_stars(buffer: &mut RenderBuffer, stars: &StarField, frame_count: u64)
     }
 }
 
-fn render_sun(buffer: &mut RenderBuffer, center_x: u16, center_y: u16) {
+fn render_sun(buffer: &mut RenderBuffer, center_x: u16, center_y: u16, theme: &Theme) {
     // Simple sun: single ☀ character
-    buffer.set(center_x, center_y, '☀', Color::Yellow);
+    buffer.set(center_x, center_y, glyphs().sun, theme.highlight);
 }
 
 /// Render subtle dotted orbit paths for owned producer tiers
@@ -199,10 +187,11 @@ fn render_orbit_paths(
     center_x: u16,
     center_y: u16,
     owned_tiers: &[usize],
+    theme: &Theme,
 ) {
     for &tier in owned_tiers {
         let radius = orbit::radius_for_tier(tier);
-        let color = Color::Rgb(40, 40, 50); // Very subtle dark color
+        let color = theme.orbit;
 
         // Draw dotted ellipse (x stretched 2x for aspect ratio)
         let circumference = (2.0 * std::f64::consts::PI * radius * 1.5) as usize;
@@ -224,7 +213,7 @@ fn render_orbit_paths(
             if px < buffer.width && py < buffer.height {
                 // Only draw if cell is empty (space)
                 if buffer.get(px, py).ch == ' ' {
-                    buffer.set(px, py, '·', color);
+                    buffer.set(px, py, glyphs().orbit, color);
                 }
             }
         }
@@ -238,12 +227,13 @@ fn render_producers(
     center_y: u16,
     frame_count: u64,
     orbital_bodies: &[OrbitalBody],
+    theme: &Theme,
 ) {
     for body in orbital_bodies {
         let (x, y) = body.position(frame_count, center_x, center_y);
 
         if x < buffer.width && y < buffer.height {
-            let color = producer_color(body.tier);
+            let color = theme.tier_color(body.tier);
             // Use the first character of the icon
             let ch = body.icon.chars().next().unwrap_or('?');
             buffer.set(x, y, ch, color);
@@ -299,13 +289,8 @@ fn render_stellar_gauge(frame: &mut Frame, area: Rect, app: &App) {
 
     // Each "layer" represents 100% of essence
     // Layer colors get progressively darker as you overflow
-    let layer_chars = ['█', '▓', '▒', '░'];
-    let layer_colors = [
-        Color::Rgb(150, 220, 255), // Layer 1 (0-100%): Bright cyan
-        Color::Rgb(100, 180, 220), // Layer 2 (100-200%): Medium cyan
-        Color::Rgb(60, 140, 180),  // Layer 3 (200-300%): Darker cyan
-        Color::Rgb(40, 100, 140),  // Layer 4 (300%+): Dark cyan
-    ];
+    let layer_chars = glyphs().gauge;
+    let layer_colors = app.theme.energy_rings;
 
     // Determine which layer (100% bracket) we're in
     // Layer 0: 0-100%, Layer 1: 100-200%, etc.
@@ -349,8 +334,8 @@ fn render_stellar_gauge(frame: &mut Frame, area: Rect, app: &App) {
     frame.buffer_mut().set_string(
         area.x,
         icon_y,
-        "☽",
-        Style::default().fg(Color::Rgb(200, 200, 255)),
+        glyphs().moon,
+        Style::default().fg(app.theme.text),
     );
 
     // Render percentage below the icon
@@ -358,9 +343,9 @@ fn render_stellar_gauge(frame: &mut Frame, area: Rect, app: &App) {
     if label_y < area.y + area.height {
         let label = format!("{:.0}%", essence_percent);
         let label_color = if essence_percent >= 100.0 {
-            Color::Rgb(150, 220, 255) // Bright when over 100%
+            layer_colors[0] // Bright when over 100%
         } else {
-            Color::Gray
+            app.theme.muted
         };
         frame
             .buffer_mut()
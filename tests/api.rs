@@ -0,0 +1,149 @@
+// Drive the JSON-RPC API of a running daemon the way an external tool would
+#![cfg(unix)]
+
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpStream;
+use std::path::Path;
+use std::process::{Child, ChildStderr, Command, Stdio};
+use std::sync::mpsc;
+use std::thread;
+
+use serde_json::{json, Value};
+
+/// Kills the daemon if the test fails before stopping it
+struct Daemon(Child);
+
+impl Drop for Daemon {
+    fn drop(&mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
+
+fn solaris(home: &Path) -> Command {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_solaris"));
+    command
+        .env("HOME", home)
+        .env_remove("XDG_DATA_HOME")
+        .stdin(Stdio::null());
+    command
+}
+
+/// Read the daemon's startup lines until it says where the API listens,
+/// returning the address and token file. The rest of its output is drained
+/// so the daemon never writes to a closed pipe.
+fn api_address(stderr: ChildStderr) -> (String, String) {
+    let (lines, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        for line in BufReader::new(stderr).lines().map_while(Result::ok) {
+            let _ = lines.send(line);
+        }
+    });
+    for line in receiver {
+        if let Some(rest) = line.strip_prefix("API listening on ") {
+            let (addr, token_path) = rest.split_once(" (token in ").unwrap();
+            return (
+                addr.to_string(),
+                token_path.trim_end_matches(')').to_string(),
+            );
+        }
+    }
+    panic!("the daemon exited without starting the API");
+}
+
+struct Client {
+    writer: TcpStream,
+    reader: BufReader<TcpStream>,
+    token: String,
+    next_id: u64,
+}
+
+impl Client {
+    fn call(&mut self, method: &str, mut params: Value) -> Value {
+        params["token"] = json!(self.token);
+        self.raw(json!({ "jsonrpc": "2.0", "method": method, "params": params }))
+    }
+
+    fn raw(&mut self, mut request: Value) -> Value {
+        self.next_id += 1;
+        request["id"] = json!(self.next_id);
+        writeln!(self.writer, "{}", request).unwrap();
+        let mut line = String::new();
+        self.reader.read_line(&mut line).unwrap();
+        let response: Value = serde_json::from_str(&line).unwrap();
+        assert_eq!(response["id"], json!(self.next_id));
+        response
+    }
+}
+
+#[test]
+fn test_api_buys_a_producer_and_saves_it() {
+    let home = tempfile::tempdir().unwrap();
+    let mut child = solaris(home.path())
+        .args(["daemon", "--api-port", "0"])
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let stderr = child.stderr.take().unwrap();
+    let mut daemon = Daemon(child);
+    let (addr, token_path) = api_address(stderr);
+
+    let token = fs::read_to_string(&token_path).unwrap().trim().to_string();
+    assert_eq!(token.len(), 64);
+    let stream = TcpStream::connect(&addr).unwrap();
+    let mut client = Client {
+        reader: BufReader::new(stream.try_clone().unwrap()),
+        writer: stream,
+        token,
+        next_id: 0,
+    };
+
+    // Without the token nothing runs
+    let denied = client.raw(json!({ "jsonrpc": "2.0", "method": "mine" }));
+    assert_eq!(denied["error"]["code"], -32001);
+    let denied = client.raw(json!({
+        "jsonrpc": "2.0",
+        "method": "mine",
+        "params": { "token": "guess" },
+    }));
+    assert_eq!(denied["error"]["code"], -32001);
+
+    let unknown = client.call("cheat", json!({}));
+    assert_eq!(unknown["error"]["code"], -32601);
+    let broke = client.call("buy_producer", json!({ "id": 1 }));
+    assert_eq!(broke["error"]["code"], -32000);
+
+    // Mine until the first producer is affordable
+    let state = client.call("get_state", json!({}))["result"].clone();
+    assert_eq!(state["label"], "main");
+    let cost = state["producers"][0]["cost"].as_f64().unwrap();
+    let mut energy = state["energy"].as_f64().unwrap();
+    for _ in 0..1000 {
+        if energy >= cost {
+            break;
+        }
+        let mined = client.call("mine", json!({}));
+        energy = mined["result"]["energy"].as_f64().unwrap();
+    }
+
+    let bought = client.call("buy_producer", json!({ "id": 1, "quantity": 1 }));
+    assert_eq!(bought["result"]["owned"], 1, "{}", bought);
+    let breakdown = client.call("get_production_breakdown", json!({}))["result"].clone();
+    assert_eq!(breakdown["producers"][0]["id"], 1);
+    assert!(breakdown["energy_per_second"].as_f64().unwrap() > 0.0);
+
+    // Stopping the daemon saves what the API bought
+    let stopped = solaris(home.path())
+        .args(["daemon", "--stop"])
+        .output()
+        .unwrap();
+    assert!(stopped.status.success());
+    daemon.0.wait().unwrap();
+
+    let saves = Path::new(&token_path).parent().unwrap().join("saves");
+    let save: Value =
+        serde_json::from_str(&fs::read_to_string(saves.join("main.json")).unwrap()).unwrap();
+    assert_eq!(save["game_state"]["producers_owned"]["1"], 1);
+}
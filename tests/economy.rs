@@ -0,0 +1,157 @@
+// Properties of the economy that must hold for any save, not just the
+// hand-picked examples in the unit tests
+use proptest::prelude::*;
+use proptest::sample::subsequence;
+use serde_json::{json, Value};
+
+use solaris::game::{
+    calculate_bulk_cost, calculate_max_affordable, Achievement, GameState, PrestigeUpgrade,
+    Producer,
+};
+
+/// Relative tolerance for sums of floating-point costs
+const EPSILON: f64 = 1e-9;
+
+fn producer() -> impl Strategy<Value = &'static Producer> {
+    (0..Producer::all().len()).prop_map(|i| &Producer::all()[i])
+}
+
+/// Ways a player can spend or earn energy between purchases
+#[derive(Debug, Clone)]
+enum Step {
+    Earn(f64),
+    Buy(u32, u64),
+}
+
+fn step() -> impl Strategy<Value = Step> {
+    prop_oneof![
+        (0.0..1e12f64).prop_map(Step::Earn),
+        (0..=Producer::all().len() as u32 + 1, 0..300u64).prop_map(|(id, n)| Step::Buy(id, n)),
+    ]
+}
+
+/// A save somewhere in its run, possibly after earlier ascensions
+fn game() -> impl Strategy<Value = GameState> {
+    let prestige_ids: Vec<u32> = PrestigeUpgrade::all().iter().map(|u| u.id).collect();
+    let achievement_ids: Vec<u32> = Achievement::all().iter().map(|a| a.id).collect();
+    (
+        0.0..1e30f64,
+        0.0..1e30f64,
+        prop::collection::hash_map(1..=Producer::all().len() as u32, 0..500u64, 0..8),
+        subsequence(prestige_ids.clone(), 0..=prestige_ids.len()),
+        subsequence(achievement_ids.clone(), 0..=achievement_ids.len()),
+        0..1_000_000u64,
+    )
+        .prop_map(
+            |(energy, earned, producers, prestige, achievements, chips)| {
+                let mut game = GameState::new();
+                game.energy = energy;
+                game.total_energy_earned = earned;
+                game.all_time_energy_earned = earned;
+                game.producers_owned = producers;
+                game.prestige_upgrades = prestige;
+                game.achievements_unlocked = achievements;
+                game.total_stellar_chips_earned = chips;
+                game
+            },
+        )
+}
+
+/// Everything an ascension promises to keep or only ever add to
+fn persistent(game: &GameState) -> Value {
+    json!({
+        "stellar_chips": game.stellar_chips,
+        "total_stellar_chips_earned": game.total_stellar_chips_earned,
+        "total_ascensions": game.total_ascensions,
+        "best_ascension_chips": game.best_ascension_chips,
+        "best_click_streak": game.best_click_streak,
+        "prestige_upgrades": game.prestige_upgrades,
+        "achievements_unlocked": game.achievements_unlocked,
+        "all_time_energy_earned": game.all_time_energy_earned,
+        "purchase_queue": game.purchase_queue,
+    })
+}
+
+proptest! {
+    #[test]
+    fn max_affordable_is_the_true_maximum(
+        producer in producer(),
+        owned in 0..400u64,
+        energy in prop_oneof![0.0..1e6f64, 0.0..1e40f64],
+        max_quantity in prop_oneof![0..100u64, Just(u64::MAX)],
+    ) {
+        let cost = |n| calculate_bulk_cost(producer.base_cost, owned, n, producer.id);
+        let n = calculate_max_affordable(producer.base_cost, owned, energy, max_quantity, producer.id);
+
+        prop_assert!(n <= max_quantity);
+        prop_assert!(cost(n) <= energy, "{} cost {} with {} energy", n, cost(n), energy);
+        if n < max_quantity {
+            prop_assert!(cost(n + 1) > energy, "{} more was affordable", n + 1);
+        }
+    }
+
+    #[test]
+    fn bulk_cost_is_monotonic(
+        producer in producer(),
+        owned in 0..1000u64,
+        quantity in 0..1000u64,
+    ) {
+        let cost = |owned, n| calculate_bulk_cost(producer.base_cost, owned, n, producer.id);
+
+        prop_assert!(cost(owned, quantity) >= 0.0);
+        prop_assert!(cost(owned, quantity + 1) > cost(owned, quantity));
+        prop_assert!(cost(owned + 1, quantity) >= cost(owned, quantity));
+    }
+
+    #[test]
+    fn bulk_cost_is_additive(
+        producer in producer(),
+        owned in 0..1000u64,
+        a in 0..500u64,
+        b in 0..500u64,
+    ) {
+        let cost = |owned, n| calculate_bulk_cost(producer.base_cost, owned, n, producer.id);
+
+        let split = cost(owned, a) + cost(owned + a, b);
+        let whole = cost(owned, a + b);
+        prop_assert!(
+            (split - whole).abs() <= whole * EPSILON,
+            "{} + {} bought separately cost {}, together {}", a, b, split, whole
+        );
+    }
+
+    #[test]
+    fn buying_never_makes_energy_negative(
+        energy in 0.0..1e9f64,
+        steps in prop::collection::vec(step(), 1..60),
+    ) {
+        let mut game = GameState::new();
+        game.energy = energy;
+        for step in steps {
+            match step {
+                Step::Earn(amount) => game.add_energy(amount),
+                Step::Buy(id, quantity) => {
+                    let before = game.energy;
+                    if !game.buy_producer(id, quantity) {
+                        prop_assert_eq!(game.energy, before);
+                    }
+                }
+            }
+            prop_assert!(game.energy >= 0.0, "{:?} left {} energy", step, game.energy);
+        }
+    }
+
+    #[test]
+    fn ascension_is_idempotent_for_persistent_fields(mut game in game()) {
+        let before = persistent(&game);
+        game.perform_ascension();
+        let once = persistent(&game);
+        game.perform_ascension();
+
+        // A fresh run has earned nothing, so a second ascension changes nothing
+        prop_assert_eq!(persistent(&game), once.clone());
+        for kept in ["prestige_upgrades", "achievements_unlocked", "all_time_energy_earned"] {
+            prop_assert_eq!(&once[kept], &before[kept]);
+        }
+    }
+}